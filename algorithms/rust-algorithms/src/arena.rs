@@ -0,0 +1,233 @@
+//! An arena that stores values in a single `Vec` and hands out index-based
+//! handles instead of `Box`/`Rc` pointers, avoiding a heap allocation per
+//! node and making the whole structure trivially serializable as one slice.
+//! Freed slots are recycled via a free list, with a generation counter on
+//! each [`NodeId`] so a handle to a freed-and-reused slot is detected
+//! instead of silently resolving to the wrong value.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NodeId {
+    index: usize,
+    generation: u32,
+}
+
+struct Slot<T> {
+    value: Option<T>,
+    generation: u32,
+}
+
+#[derive(Default)]
+pub struct Arena<T> {
+    slots: Vec<Slot<T>>,
+    free: Vec<usize>,
+}
+
+impl<T> Arena<T> {
+    pub fn new() -> Self {
+        Arena {
+            slots: Vec::new(),
+            free: Vec::new(),
+        }
+    }
+
+    /// Stores `value`, reusing a freed slot if one is available.
+    pub fn alloc(&mut self, value: T) -> NodeId {
+        if let Some(index) = self.free.pop() {
+            let slot = &mut self.slots[index];
+            slot.value = Some(value);
+            NodeId {
+                index,
+                generation: slot.generation,
+            }
+        } else {
+            let index = self.slots.len();
+            self.slots.push(Slot {
+                value: Some(value),
+                generation: 0,
+            });
+            NodeId {
+                index,
+                generation: 0,
+            }
+        }
+    }
+
+    /// Frees `id`'s slot for reuse. Returns `false` if `id` is stale
+    /// (already freed, or from a slot since reallocated).
+    pub fn free(&mut self, id: NodeId) -> bool {
+        match self.slots.get_mut(id.index) {
+            Some(slot) if slot.generation == id.generation && slot.value.is_some() => {
+                slot.value = None;
+                slot.generation += 1;
+                self.free.push(id.index);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn get(&self, id: NodeId) -> Option<&T> {
+        let slot = self.slots.get(id.index)?;
+        if slot.generation == id.generation {
+            slot.value.as_ref()
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, id: NodeId) -> Option<&mut T> {
+        let slot = self.slots.get_mut(id.index)?;
+        if slot.generation == id.generation {
+            slot.value.as_mut()
+        } else {
+            None
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.slots.len() - self.free.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// A BST node holding its value and its children as arena indices instead
+/// of `Box<BstNode<T>>`.
+struct BstNode<T> {
+    value: T,
+    left: Option<NodeId>,
+    right: Option<NodeId>,
+}
+
+/// A binary search tree whose nodes live in an [`Arena`], so inserting
+/// doesn't allocate per-node and traversal is plain index-chasing. Never
+/// frees nodes, so every lookup by an id this tree produced is expected
+/// to succeed.
+pub struct ArenaBst<T: Ord> {
+    arena: Arena<BstNode<T>>,
+    root: Option<NodeId>,
+}
+
+impl<T: Ord> Default for ArenaBst<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord> ArenaBst<T> {
+    pub fn new() -> Self {
+        ArenaBst {
+            arena: Arena::new(),
+            root: None,
+        }
+    }
+
+    pub fn insert(&mut self, value: T) {
+        let Some(root) = self.root else {
+            self.root = Some(self.arena.alloc(BstNode {
+                value,
+                left: None,
+                right: None,
+            }));
+            return;
+        };
+        self.insert_from(root, value);
+    }
+
+    fn insert_from(&mut self, current: NodeId, value: T) {
+        let go_left = value < self.node(current).value;
+        let existing_child = if go_left {
+            self.node(current).left
+        } else {
+            self.node(current).right
+        };
+
+        match existing_child {
+            Some(next) => self.insert_from(next, value),
+            None => {
+                let new_id = self.arena.alloc(BstNode {
+                    value,
+                    left: None,
+                    right: None,
+                });
+                let node = self.arena.get_mut(current).expect("node inserted by this tree");
+                if go_left {
+                    node.left = Some(new_id);
+                } else {
+                    node.right = Some(new_id);
+                }
+            }
+        }
+    }
+
+    fn node(&self, id: NodeId) -> &BstNode<T> {
+        self.arena.get(id).expect("node inserted by this tree")
+    }
+
+    /// Collects every value in sorted order via an in-order traversal.
+    pub fn in_order(&self) -> Vec<&T> {
+        let mut values = Vec::with_capacity(self.arena.len());
+        self.in_order_from(self.root, &mut values);
+        values
+    }
+
+    fn in_order_from<'a>(&'a self, id: Option<NodeId>, values: &mut Vec<&'a T>) {
+        let Some(id) = id else { return };
+        let node = self.node(id);
+        self.in_order_from(node.left, values);
+        values.push(&node.value);
+        self.in_order_from(node.right, values);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn in_order_traversal_of_an_arena_backed_bst_is_sorted() {
+        let mut tree = ArenaBst::new();
+        for value in [5, 2, 8, 1, 3, 7, 9] {
+            tree.insert(value);
+        }
+
+        assert_eq!(tree.in_order(), vec![&1, &2, &3, &5, &7, &8, &9]);
+    }
+
+    #[test]
+    fn alloc_hands_out_ids_and_get_returns_the_stored_value() {
+        let mut arena = Arena::new();
+        let a = arena.alloc("a");
+        let b = arena.alloc("b");
+
+        assert_eq!(arena.get(a), Some(&"a"));
+        assert_eq!(arena.get(b), Some(&"b"));
+        assert_eq!(arena.len(), 2);
+    }
+
+    #[test]
+    fn freeing_a_slot_lets_the_next_alloc_reuse_it() {
+        let mut arena = Arena::new();
+        let a = arena.alloc("a");
+
+        assert!(arena.free(a));
+        assert_eq!(arena.len(), 0);
+
+        let b = arena.alloc("b");
+        assert_eq!(arena.len(), 1);
+        assert_eq!(arena.get(b), Some(&"b"));
+    }
+
+    #[test]
+    fn an_id_from_before_a_free_fails_generation_validation_after_reuse() {
+        let mut arena = Arena::new();
+        let a = arena.alloc("a");
+        arena.free(a);
+        arena.alloc("b");
+
+        assert_eq!(arena.get(a), None);
+        assert!(!arena.free(a));
+    }
+}
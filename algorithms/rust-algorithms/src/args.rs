@@ -0,0 +1,109 @@
+//! A tiny command-line-style argument parser: `--key value` pairs,
+//! `--flag` booleans, and positional arguments, tying together the
+//! parsing patterns used elsewhere in this crate.
+
+use std::collections::HashMap;
+use std::fmt;
+
+/// Flags known to take a value rather than acting as a boolean switch.
+const VALUE_FLAGS: &[&str] = &["--port", "--host", "--output"];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsedArgs {
+    pub values: HashMap<String, String>,
+    pub flags: HashMap<String, bool>,
+    pub positional: Vec<String>,
+}
+
+impl ParsedArgs {
+    pub fn value(&self, key: &str) -> Option<&str> {
+        self.values.get(key).map(String::as_str)
+    }
+
+    pub fn flag(&self, key: &str) -> bool {
+        self.flags.get(key).copied().unwrap_or(false)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ArgError {
+    UnknownFlag(String),
+    MissingValue(String),
+}
+
+impl fmt::Display for ArgError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ArgError::UnknownFlag(flag) => write!(f, "unknown flag `{flag}`"),
+            ArgError::MissingValue(flag) => write!(f, "missing value for `{flag}`"),
+        }
+    }
+}
+
+impl std::error::Error for ArgError {}
+
+/// Parses `args` into value flags (`--key value`), boolean flags
+/// (`--flag`), and positional arguments.
+pub fn parse_args(args: &[&str]) -> Result<ParsedArgs, ArgError> {
+    let mut values = HashMap::new();
+    let mut flags = HashMap::new();
+    let mut positional = Vec::new();
+
+    let mut i = 0;
+    while i < args.len() {
+        let arg = args[i];
+        if let Some(key) = arg.strip_prefix("--") {
+            if VALUE_FLAGS.contains(&arg) {
+                let value = args
+                    .get(i + 1)
+                    .ok_or_else(|| ArgError::MissingValue(arg.to_string()))?;
+                values.insert(key.to_string(), value.to_string());
+                i += 2;
+            } else if KNOWN_FLAGS.contains(&arg) {
+                flags.insert(key.to_string(), true);
+                i += 1;
+            } else {
+                return Err(ArgError::UnknownFlag(arg.to_string()));
+            }
+        } else {
+            positional.push(arg.to_string());
+            i += 1;
+        }
+    }
+
+    Ok(ParsedArgs {
+        values,
+        flags,
+        positional,
+    })
+}
+
+/// Boolean flags this parser recognizes; anything else starting with `--`
+/// is an [`ArgError::UnknownFlag`].
+const KNOWN_FLAGS: &[&str] = &["--debug", "--verbose"];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_value_flags_boolean_flags_and_positional_args() {
+        let parsed = parse_args(&["--port", "8080", "--debug", "input.txt"]).unwrap();
+
+        assert_eq!(parsed.value("port"), Some("8080"));
+        assert!(parsed.flag("debug"));
+        assert_eq!(parsed.positional, vec!["input.txt".to_string()]);
+    }
+
+    #[test]
+    fn errors_on_an_unknown_flag() {
+        let err = parse_args(&["--bogus"]).unwrap_err();
+        assert_eq!(err, ArgError::UnknownFlag("--bogus".to_string()));
+    }
+
+    #[test]
+    fn errors_when_a_value_flag_has_nothing_after_it() {
+        let err = parse_args(&["--port"]).unwrap_err();
+        assert_eq!(err, ArgError::MissingValue("--port".to_string()));
+    }
+}
@@ -0,0 +1,158 @@
+//! An [`Observer`] that buffers changes and only forwards them downstream
+//! once a count or time threshold is crossed, trading immediacy for fewer,
+//! larger notifications.
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::observer::Observer;
+use crate::rate_limiter::{Clock, SystemClock};
+
+struct Buffer {
+    values: Vec<i32>,
+    opened_at: Option<Instant>,
+}
+
+/// Accumulates values passed to [`Observer::on_change`] and flushes them
+/// as a single batch to `downstream` once `max_count` values are buffered
+/// or `max_age` has passed since the first buffered value.
+pub struct BatchingObserver<C: Clock = SystemClock> {
+    max_count: usize,
+    max_age: Duration,
+    clock: C,
+    buffer: Mutex<Buffer>,
+    downstream: Box<dyn Fn(Vec<i32>) + Send + Sync>,
+}
+
+impl BatchingObserver<SystemClock> {
+    pub fn new<F: Fn(Vec<i32>) + Send + Sync + 'static>(
+        max_count: usize,
+        max_age: Duration,
+        downstream: F,
+    ) -> Self {
+        BatchingObserver::with_clock(max_count, max_age, SystemClock, downstream)
+    }
+}
+
+impl<C: Clock> BatchingObserver<C> {
+    pub fn with_clock<F: Fn(Vec<i32>) + Send + Sync + 'static>(
+        max_count: usize,
+        max_age: Duration,
+        clock: C,
+        downstream: F,
+    ) -> Self {
+        BatchingObserver {
+            max_count,
+            max_age,
+            clock,
+            buffer: Mutex::new(Buffer {
+                values: Vec::new(),
+                opened_at: None,
+            }),
+            downstream: Box::new(downstream),
+        }
+    }
+
+    /// Flushes whatever is currently buffered, even if no threshold has
+    /// been crossed. A no-op if the buffer is empty.
+    pub fn force_flush(&self) {
+        let mut buffer = self.buffer.lock().expect("buffer mutex poisoned");
+        if buffer.values.is_empty() {
+            return;
+        }
+        let batch = std::mem::take(&mut buffer.values);
+        buffer.opened_at = None;
+        (self.downstream)(batch);
+    }
+}
+
+impl<C: Clock> Observer for BatchingObserver<C> {
+    fn on_change(&self, value: i32) {
+        let should_flush = {
+            let mut buffer = self.buffer.lock().expect("buffer mutex poisoned");
+            let now = self.clock.now();
+            buffer.values.push(value);
+            let opened_at = *buffer.opened_at.get_or_insert(now);
+
+            buffer.values.len() >= self.max_count || now.duration_since(opened_at) >= self.max_age
+        };
+
+        if should_flush {
+            self.force_flush();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    use std::sync::{Arc, Mutex as StdMutex};
+
+    struct MockClock {
+        now: Cell<Instant>,
+    }
+
+    impl MockClock {
+        fn new() -> Self {
+            MockClock {
+                now: Cell::new(Instant::now()),
+            }
+        }
+
+        fn advance(&self, by: Duration) {
+            self.now.set(self.now.get() + by);
+        }
+    }
+
+    impl Clock for &MockClock {
+        fn now(&self) -> Instant {
+            self.now.get()
+        }
+    }
+
+    #[test]
+    fn flushes_once_the_count_threshold_is_crossed() {
+        let flushes = Arc::new(StdMutex::new(Vec::new()));
+        let collected = Arc::clone(&flushes);
+        let observer =
+            BatchingObserver::new(3, Duration::from_secs(60), move |batch| collected.lock().unwrap().push(batch));
+
+        observer.on_change(1);
+        observer.on_change(2);
+        assert!(flushes.lock().unwrap().is_empty());
+
+        observer.on_change(3);
+        assert_eq!(*flushes.lock().unwrap(), vec![vec![1, 2, 3]]);
+    }
+
+    #[test]
+    fn flushes_once_the_age_threshold_is_crossed() {
+        let clock = MockClock::new();
+        let flushes = Arc::new(StdMutex::new(Vec::new()));
+        let collected = Arc::clone(&flushes);
+        let observer = BatchingObserver::with_clock(100, Duration::from_millis(50), &clock, move |batch| {
+            collected.lock().unwrap().push(batch)
+        });
+
+        observer.on_change(1);
+        clock.advance(Duration::from_millis(60));
+        observer.on_change(2);
+
+        assert_eq!(*flushes.lock().unwrap(), vec![vec![1, 2]]);
+    }
+
+    #[test]
+    fn force_flush_drains_a_partial_batch() {
+        let flushes = Arc::new(StdMutex::new(Vec::new()));
+        let collected = Arc::clone(&flushes);
+        let observer =
+            BatchingObserver::new(10, Duration::from_secs(60), move |batch| collected.lock().unwrap().push(batch));
+
+        observer.on_change(1);
+        observer.on_change(2);
+        observer.force_flush();
+
+        assert_eq!(*flushes.lock().unwrap(), vec![vec![1, 2]]);
+    }
+}
@@ -0,0 +1,281 @@
+//! A small binary serialization format. Fixed-width types encode as
+//! themselves; variable-length types (`String`, `Vec<T>`) are prefixed
+//! with a `u32` length. [`to_bytes`]/[`from_bytes`] round-trip any type
+//! implementing both traits.
+
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use crate::tree::TreeNode;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodeError {
+    pub message: String,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+fn end_of_buffer(what: &str) -> DecodeError {
+    DecodeError {
+        message: format!("unexpected end of buffer while reading {what}"),
+    }
+}
+
+pub trait BinSerialize {
+    fn serialize(&self, out: &mut Vec<u8>);
+}
+
+pub trait BinDeserialize: Sized {
+    /// Reads `Self` from the front of `bytes`, returning the value and how
+    /// many bytes it consumed.
+    fn deserialize(bytes: &[u8]) -> Result<(Self, usize), DecodeError>;
+}
+
+pub fn to_bytes<T: BinSerialize>(value: &T) -> Vec<u8> {
+    let mut out = Vec::new();
+    value.serialize(&mut out);
+    out
+}
+
+/// Decodes a `T`, requiring `bytes` to be entirely consumed so trailing
+/// garbage isn't silently ignored.
+pub fn from_bytes<T: BinDeserialize>(bytes: &[u8]) -> Result<T, DecodeError> {
+    let (value, consumed) = T::deserialize(bytes)?;
+    if consumed != bytes.len() {
+        return Err(DecodeError {
+            message: "trailing bytes after decoding".to_string(),
+        });
+    }
+    Ok(value)
+}
+
+macro_rules! impl_fixed_width {
+    ($ty:ty, $len:expr) => {
+        impl BinSerialize for $ty {
+            fn serialize(&self, out: &mut Vec<u8>) {
+                out.extend_from_slice(&self.to_le_bytes());
+            }
+        }
+
+        impl BinDeserialize for $ty {
+            fn deserialize(bytes: &[u8]) -> Result<(Self, usize), DecodeError> {
+                let slice = bytes
+                    .get(..$len)
+                    .ok_or_else(|| end_of_buffer(stringify!($ty)))?;
+                let array: [u8; $len] = slice.try_into().expect("slice has exactly $len bytes");
+                Ok((<$ty>::from_le_bytes(array), $len))
+            }
+        }
+    };
+}
+
+impl_fixed_width!(u32, 4);
+impl_fixed_width!(u64, 8);
+impl_fixed_width!(i32, 4);
+impl_fixed_width!(i64, 8);
+impl_fixed_width!(f64, 8);
+
+impl BinSerialize for bool {
+    fn serialize(&self, out: &mut Vec<u8>) {
+        out.push(u8::from(*self));
+    }
+}
+
+impl BinDeserialize for bool {
+    fn deserialize(bytes: &[u8]) -> Result<(Self, usize), DecodeError> {
+        let byte = *bytes.first().ok_or_else(|| end_of_buffer("bool"))?;
+        Ok((byte != 0, 1))
+    }
+}
+
+impl BinSerialize for String {
+    fn serialize(&self, out: &mut Vec<u8>) {
+        (self.len() as u32).serialize(out);
+        out.extend_from_slice(self.as_bytes());
+    }
+}
+
+impl BinDeserialize for String {
+    fn deserialize(bytes: &[u8]) -> Result<(Self, usize), DecodeError> {
+        let (len, prefix_len) = u32::deserialize(bytes)?;
+        let len = len as usize;
+        let data = bytes
+            .get(prefix_len..prefix_len + len)
+            .ok_or_else(|| end_of_buffer("String"))?;
+        let value = String::from_utf8(data.to_vec())
+            .map_err(|_| DecodeError {
+                message: "invalid utf-8 in String".to_string(),
+            })?;
+        Ok((value, prefix_len + len))
+    }
+}
+
+impl<T: BinSerialize> BinSerialize for Vec<T> {
+    fn serialize(&self, out: &mut Vec<u8>) {
+        (self.len() as u32).serialize(out);
+        for item in self {
+            item.serialize(out);
+        }
+    }
+}
+
+impl<T: BinDeserialize> BinDeserialize for Vec<T> {
+    fn deserialize(bytes: &[u8]) -> Result<(Self, usize), DecodeError> {
+        let (count, mut consumed) = u32::deserialize(bytes)?;
+        let mut items = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            let (item, item_len) = T::deserialize(&bytes[consumed..])?;
+            items.push(item);
+            consumed += item_len;
+        }
+        Ok((items, consumed))
+    }
+}
+
+impl<T: BinSerialize> BinSerialize for TreeNode<T> {
+    fn serialize(&self, out: &mut Vec<u8>) {
+        self.value.serialize(out);
+        serialize_child(&self.left, out);
+        serialize_child(&self.right, out);
+    }
+}
+
+fn serialize_child<T: BinSerialize>(child: &Option<Box<TreeNode<T>>>, out: &mut Vec<u8>) {
+    match child {
+        Some(node) => {
+            out.push(1);
+            node.serialize(out);
+        }
+        None => out.push(0),
+    }
+}
+
+impl<T: BinDeserialize> BinDeserialize for TreeNode<T> {
+    fn deserialize(bytes: &[u8]) -> Result<(Self, usize), DecodeError> {
+        let (value, mut consumed) = T::deserialize(bytes)?;
+        let (left, left_len) = deserialize_child::<T>(&bytes[consumed..])?;
+        consumed += left_len;
+        let (right, right_len) = deserialize_child::<T>(&bytes[consumed..])?;
+        consumed += right_len;
+        Ok((
+            TreeNode {
+                value,
+                left,
+                right,
+            },
+            consumed,
+        ))
+    }
+}
+
+type ChildDecode<T> = (Option<Box<TreeNode<T>>>, usize);
+
+fn deserialize_child<T: BinDeserialize>(bytes: &[u8]) -> Result<ChildDecode<T>, DecodeError> {
+    let marker = *bytes.first().ok_or_else(|| end_of_buffer("child marker"))?;
+    match marker {
+        0 => Ok((None, 1)),
+        1 => {
+            let (node, node_len) = TreeNode::<T>::deserialize(&bytes[1..])?;
+            Ok((Some(Box::new(node)), 1 + node_len))
+        }
+        _ => Err(DecodeError {
+            message: format!("invalid child marker `{marker}`"),
+        }),
+    }
+}
+
+/// Encodes any `Display` value as its text representation, length-prefixed
+/// like [`String`], so it round-trips through [`decode_parse`] without
+/// each type needing its own [`BinSerialize`] impl.
+pub fn encode_display<T: Display>(value: &T) -> Vec<u8> {
+    to_bytes(&value.to_string())
+}
+
+/// Decodes bytes produced by [`encode_display`] via `T::from_str`.
+pub fn decode_parse<T: FromStr>(bytes: &[u8]) -> Result<T, DecodeError> {
+    let text: String = from_bytes(bytes)?;
+    text.parse().map_err(|_| DecodeError {
+        message: format!("`{text}` could not be parsed as the target type"),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_a_tree_through_bytes() {
+        let tree = TreeNode::with_children(
+            1u32,
+            Some(TreeNode::leaf(2u32)),
+            Some(TreeNode::with_children(3u32, Some(TreeNode::leaf(4u32)), None)),
+        );
+
+        let bytes = to_bytes(&tree);
+        let decoded: TreeNode<u32> = from_bytes(&bytes).expect("valid encoding");
+
+        assert_eq!(decoded, tree);
+    }
+
+    #[test]
+    fn round_trips_a_vec_of_strings() {
+        let values = vec!["hello".to_string(), "world".to_string()];
+        let bytes = to_bytes(&values);
+        let decoded: Vec<String> = from_bytes(&bytes).expect("valid encoding");
+        assert_eq!(decoded, values);
+    }
+
+    #[test]
+    fn a_truncated_buffer_errors_cleanly_instead_of_panicking() {
+        let tree = TreeNode::leaf(1u32);
+        let mut bytes = to_bytes(&tree);
+        bytes.truncate(bytes.len() - 1);
+
+        assert!(from_bytes::<TreeNode<u32>>(&bytes).is_err());
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    impl fmt::Display for Point {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{},{}", self.x, self.y)
+        }
+    }
+
+    impl std::str::FromStr for Point {
+        type Err = ();
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let (x, y) = s.split_once(',').ok_or(())?;
+            Ok(Point {
+                x: x.parse().map_err(|_| ())?,
+                y: y.parse().map_err(|_| ())?,
+            })
+        }
+    }
+
+    #[test]
+    fn round_trips_an_f64_via_display_and_from_str() {
+        let bytes = encode_display(&3.5_f64);
+        let decoded: f64 = decode_parse(&bytes).expect("valid encoding");
+        assert_eq!(decoded, 3.5);
+    }
+
+    #[test]
+    fn round_trips_a_custom_display_from_str_type() {
+        let point = Point { x: 3, y: 4 };
+        let bytes = encode_display(&point);
+        let decoded: Point = decode_parse(&bytes).expect("valid encoding");
+        assert_eq!(decoded, point);
+    }
+}
@@ -0,0 +1,132 @@
+//! A compact bitset backed by `Vec<u64>`, growing automatically as bits
+//! past the current capacity are set.
+
+const BITS_PER_WORD: usize = u64::BITS as usize;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BitSet {
+    words: Vec<u64>,
+}
+
+impl BitSet {
+    pub fn new() -> Self {
+        BitSet { words: Vec::new() }
+    }
+
+    pub fn with_capacity(bits: usize) -> Self {
+        BitSet {
+            words: vec![0; bits.div_ceil(BITS_PER_WORD)],
+        }
+    }
+
+    /// Sets bit `i`, growing the backing storage if needed.
+    pub fn set(&mut self, i: usize) {
+        let word = i / BITS_PER_WORD;
+        if word >= self.words.len() {
+            self.words.resize(word + 1, 0);
+        }
+        self.words[word] |= 1 << (i % BITS_PER_WORD);
+    }
+
+    pub fn clear(&mut self, i: usize) {
+        let word = i / BITS_PER_WORD;
+        if word < self.words.len() {
+            self.words[word] &= !(1 << (i % BITS_PER_WORD));
+        }
+    }
+
+    pub fn contains(&self, i: usize) -> bool {
+        let word = i / BITS_PER_WORD;
+        self.words
+            .get(word)
+            .is_some_and(|w| w & (1 << (i % BITS_PER_WORD)) != 0)
+    }
+
+    pub fn count_ones(&self) -> u32 {
+        self.words.iter().map(|w| w.count_ones()).sum()
+    }
+
+    pub fn union(&self, other: &BitSet) -> BitSet {
+        self.combine(other, |a, b| a | b)
+    }
+
+    pub fn intersection(&self, other: &BitSet) -> BitSet {
+        self.combine(other, |a, b| a & b)
+    }
+
+    pub fn difference(&self, other: &BitSet) -> BitSet {
+        self.combine(other, |a, b| a & !b)
+    }
+
+    fn combine(&self, other: &BitSet, op: impl Fn(u64, u64) -> u64) -> BitSet {
+        let len = self.words.len().max(other.words.len());
+        let words = (0..len)
+            .map(|i| {
+                let a = self.words.get(i).copied().unwrap_or(0);
+                let b = other.words.get(i).copied().unwrap_or(0);
+                op(a, b)
+            })
+            .collect();
+        BitSet { words }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn set_and_contains_track_individual_bits_across_words() {
+        let mut set = BitSet::new();
+        set.set(3);
+        set.set(70);
+
+        assert!(set.contains(3));
+        assert!(set.contains(70));
+        assert!(!set.contains(4));
+        assert_eq!(set.count_ones(), 2);
+    }
+
+    #[test]
+    fn clear_unsets_a_bit() {
+        let mut set = BitSet::new();
+        set.set(5);
+        set.clear(5);
+        assert!(!set.contains(5));
+    }
+
+    #[test]
+    fn set_operations_match_a_hashset_oracle() {
+        let mut a = BitSet::new();
+        let mut oracle_a: HashSet<usize> = HashSet::new();
+        for bit in [1, 3, 5, 70] {
+            a.set(bit);
+            oracle_a.insert(bit);
+        }
+
+        let mut b = BitSet::new();
+        let mut oracle_b: HashSet<usize> = HashSet::new();
+        for bit in [3, 5, 90] {
+            b.set(bit);
+            oracle_b.insert(bit);
+        }
+
+        let to_set = |set: &BitSet, max: usize| -> HashSet<usize> {
+            (0..max).filter(|&i| set.contains(i)).collect()
+        };
+
+        assert_eq!(
+            to_set(&a.union(&b), 128),
+            oracle_a.union(&oracle_b).copied().collect()
+        );
+        assert_eq!(
+            to_set(&a.intersection(&b), 128),
+            oracle_a.intersection(&oracle_b).copied().collect()
+        );
+        assert_eq!(
+            to_set(&a.difference(&b), 128),
+            oracle_a.difference(&oracle_b).copied().collect()
+        );
+    }
+}
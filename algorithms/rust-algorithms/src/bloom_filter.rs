@@ -0,0 +1,102 @@
+//! A Bloom filter for approximate membership tests: `might_contain` never
+//! returns a false negative, but may return a false positive at roughly
+//! the configured rate.
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+pub struct BloomFilter {
+    bits: Vec<bool>,
+    hash_count: u32,
+}
+
+impl BloomFilter {
+    /// Sizes the bit array and hash count for `expected_items` entries at
+    /// a target `false_positive_rate`, using the standard optimal-size
+    /// formulas.
+    pub fn with_params(expected_items: usize, false_positive_rate: f64) -> Self {
+        let expected_items = expected_items.max(1) as f64;
+        let ln2_squared = std::f64::consts::LN_2 * std::f64::consts::LN_2;
+
+        let bit_count = (-expected_items * false_positive_rate.ln() / ln2_squared).ceil() as usize;
+        let bit_count = bit_count.max(1);
+        let hash_count = ((bit_count as f64 / expected_items) * std::f64::consts::LN_2).round() as u32;
+        let hash_count = hash_count.max(1);
+
+        BloomFilter {
+            bits: vec![false; bit_count],
+            hash_count,
+        }
+    }
+
+    pub fn insert(&mut self, item: &str) {
+        let indices: Vec<usize> = self.hash_indices(item).collect();
+        for index in indices {
+            self.bits[index] = true;
+        }
+    }
+
+    /// Never a false negative: if this returns `false`, `item` was
+    /// definitely never inserted.
+    pub fn might_contain(&self, item: &str) -> bool {
+        self.hash_indices(item).all(|index| self.bits[index])
+    }
+
+    fn hash_indices(&self, item: &str) -> impl Iterator<Item = usize> + '_ {
+        let (h1, h2) = double_hash(item);
+        let len = self.bits.len() as u64;
+        (0..self.hash_count).map(move |i| (h1.wrapping_add(i as u64).wrapping_mul(h2) % len) as usize)
+    }
+}
+
+/// Two independent-ish hashes combined into `hash_count` derived hashes
+/// via the standard double-hashing trick, avoiding `hash_count` separate
+/// hash functions.
+fn double_hash(item: &str) -> (u64, u64) {
+    let mut h1 = DefaultHasher::new();
+    item.hash(&mut h1);
+    let first = h1.finish();
+
+    let mut h2 = DefaultHasher::new();
+    (item, "salt").hash(&mut h2);
+    let second = h2.finish() | 1;
+
+    (first, second)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_inserted_item_might_contain_is_true() {
+        let mut filter = BloomFilter::with_params(100, 0.01);
+        let items = ["apple", "banana", "cherry", "date", "elderberry"];
+        for item in items {
+            filter.insert(item);
+        }
+
+        for item in items {
+            assert!(filter.might_contain(item));
+        }
+    }
+
+    #[test]
+    fn the_false_positive_rate_stays_roughly_within_the_configured_bound() {
+        let mut filter = BloomFilter::with_params(1000, 0.01);
+        for i in 0..1000 {
+            filter.insert(&format!("member-{i}"));
+        }
+
+        let mut false_positives = 0;
+        let trials = 10_000;
+        for i in 0..trials {
+            if filter.might_contain(&format!("absent-{i}")) {
+                false_positives += 1;
+            }
+        }
+
+        let rate = false_positives as f64 / trials as f64;
+        assert!(rate < 0.05, "false positive rate was {rate}");
+    }
+}
@@ -0,0 +1,80 @@
+//! A declarative `builder!` macro generating a per-field-setter builder for
+//! a plain data struct, filling in a default for any field left unset.
+//! `macro_rules!` can't concatenate identifiers on stable, so the builder
+//! type's name is passed explicitly rather than derived from the struct's.
+
+/// Declares `$name` with the given fields, plus a `$builder_name` type with
+/// one setter per field and a `build()` that defaults anything unset.
+///
+/// `#[macro_export]` puts this at the crate root (`crate::builder!`)
+/// rather than `builder_macro::builder!`, which is how `macro_rules!`
+/// exporting works without the 2018 `pub use` re-export dance.
+#[macro_export]
+macro_rules! builder {
+    (
+        $struct_vis:vis struct $name:ident, builder $builder_name:ident {
+            $($field_vis:vis $field:ident : $ty:ty = $default:expr),* $(,)?
+        }
+    ) => {
+        $struct_vis struct $name {
+            $($field_vis $field: $ty),*
+        }
+
+        #[derive(Default)]
+        $struct_vis struct $builder_name {
+            $($field_vis $field: Option<$ty>),*
+        }
+
+        impl $builder_name {
+            pub fn new() -> Self {
+                Self::default()
+            }
+
+            $(
+                pub fn $field(mut self, value: $ty) -> Self {
+                    self.$field = Some(value);
+                    self
+                }
+            )*
+
+            pub fn build(self) -> $name {
+                $name {
+                    $($field: self.$field.unwrap_or_else(|| $default)),*
+                }
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    crate::builder! {
+        struct Widget, builder WidgetBuilder {
+            width: u32 = 100,
+            height: u32 = 50,
+            label: String = String::from("untitled"),
+        }
+    }
+
+    #[test]
+    fn unset_fields_take_their_declared_defaults() {
+        let widget = WidgetBuilder::new().width(200).build();
+
+        assert_eq!(widget.width, 200);
+        assert_eq!(widget.height, 50);
+        assert_eq!(widget.label, "untitled");
+    }
+
+    #[test]
+    fn every_field_can_be_overridden() {
+        let widget = WidgetBuilder::new()
+            .width(10)
+            .height(20)
+            .label("banner".to_string())
+            .build();
+
+        assert_eq!(widget.width, 10);
+        assert_eq!(widget.height, 20);
+        assert_eq!(widget.label, "banner");
+    }
+}
@@ -0,0 +1,103 @@
+//! A string-keyed map that treats keys case-insensitively (`Host` and
+//! `HOST` are the same entry), which the `Config`-style use cases in this
+//! crate need to avoid casing bugs.
+
+use std::collections::HashMap;
+
+/// Stores values under a lowercase-normalized key while remembering the
+/// first-inserted casing of that key for iteration.
+#[derive(Default)]
+pub struct CaseInsensitiveMap<V> {
+    entries: HashMap<String, (String, V)>,
+}
+
+impl<V> CaseInsensitiveMap<V> {
+    pub fn new() -> Self {
+        CaseInsensitiveMap {
+            entries: HashMap::new(),
+        }
+    }
+
+    /// Inserts `value` under `key`, normalized case-insensitively. If an
+    /// entry already exists for this key, its original casing is kept and
+    /// only the value is replaced.
+    pub fn insert(&mut self, key: impl Into<String>, value: V) -> Option<V> {
+        let key = key.into();
+        let normalized = key.to_lowercase();
+        match self.entries.remove(&normalized) {
+            Some((original_key, old_value)) => {
+                self.entries.insert(normalized, (original_key, value));
+                Some(old_value)
+            }
+            None => {
+                self.entries.insert(normalized, (key, value));
+                None
+            }
+        }
+    }
+
+    pub fn get(&self, key: &str) -> Option<&V> {
+        self.entries
+            .get(&key.to_lowercase())
+            .map(|(_, value)| value)
+    }
+
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.entries.contains_key(&key.to_lowercase())
+    }
+
+    pub fn remove(&mut self, key: &str) -> Option<V> {
+        self.entries.remove(&key.to_lowercase()).map(|(_, value)| value)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Iterates over `(original_key, value)` pairs, preserving the casing
+    /// the key was first inserted with.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &V)> {
+        self.entries
+            .values()
+            .map(|(original_key, value)| (original_key.as_str(), value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lookup_is_case_insensitive() {
+        let mut map = CaseInsensitiveMap::new();
+        map.insert("Host", "example.com");
+
+        assert_eq!(map.get("HOST"), Some(&"example.com"));
+        assert_eq!(map.get("host"), Some(&"example.com"));
+    }
+
+    #[test]
+    fn iteration_preserves_the_originally_inserted_casing() {
+        let mut map = CaseInsensitiveMap::new();
+        map.insert("Host", "example.com");
+        map.insert("Port", "8080");
+
+        let mut keys: Vec<&str> = map.iter().map(|(key, _)| key).collect();
+        keys.sort();
+        assert_eq!(keys, vec!["Host", "Port"]);
+    }
+
+    #[test]
+    fn reinserting_with_different_casing_keeps_the_original_key() {
+        let mut map = CaseInsensitiveMap::new();
+        map.insert("Host", "example.com");
+        map.insert("HOST", "example.org");
+
+        assert_eq!(map.get("host"), Some(&"example.org"));
+        assert_eq!(map.iter().next().map(|(key, _)| key), Some("Host"));
+    }
+}
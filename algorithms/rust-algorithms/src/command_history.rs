@@ -0,0 +1,104 @@
+//! A command log supporting undo/redo: each applied command is kept
+//! around so `undo` can replay its inverse, and `redo` can reapply it.
+
+pub trait Command<T> {
+    fn apply(&self, target: &mut T);
+    fn unapply(&self, target: &mut T);
+}
+
+#[derive(Default)]
+pub struct CommandHistory<T> {
+    applied: Vec<Box<dyn Command<T>>>,
+    undone: Vec<Box<dyn Command<T>>>,
+}
+
+impl<T> CommandHistory<T> {
+    pub fn new() -> Self {
+        CommandHistory {
+            applied: Vec::new(),
+            undone: Vec::new(),
+        }
+    }
+
+    /// Applies `command` to `target` and records it, discarding any redo
+    /// history since it's no longer reachable.
+    pub fn apply(&mut self, command: Box<dyn Command<T>>, target: &mut T) {
+        command.apply(target);
+        self.applied.push(command);
+        self.undone.clear();
+    }
+
+    /// Reverts the most recently applied command, if any.
+    pub fn undo(&mut self, target: &mut T) -> bool {
+        match self.applied.pop() {
+            Some(command) => {
+                command.unapply(target);
+                self.undone.push(command);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Reapplies the most recently undone command, if any.
+    pub fn redo(&mut self, target: &mut T) -> bool {
+        match self.undone.pop() {
+            Some(command) => {
+                command.apply(target);
+                self.applied.push(command);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Insert {
+        position: usize,
+        text: String,
+    }
+
+    impl Command<String> for Insert {
+        fn apply(&self, target: &mut String) {
+            target.insert_str(self.position, &self.text);
+        }
+
+        fn unapply(&self, target: &mut String) {
+            target.replace_range(self.position..self.position + self.text.len(), "");
+        }
+    }
+
+    #[test]
+    fn undo_reverts_an_insert_and_redo_reapplies_it() {
+        let mut document = String::from("Hello world");
+        let mut history = CommandHistory::new();
+
+        history.apply(
+            Box::new(Insert {
+                position: 5,
+                text: ", there".to_string(),
+            }),
+            &mut document,
+        );
+        assert_eq!(document, "Hello, there world");
+
+        assert!(history.undo(&mut document));
+        assert_eq!(document, "Hello world");
+
+        assert!(history.redo(&mut document));
+        assert_eq!(document, "Hello, there world");
+    }
+
+    #[test]
+    fn undo_and_redo_report_false_when_there_is_nothing_to_do() {
+        let mut document = String::from("x");
+        let mut history: CommandHistory<String> = CommandHistory::new();
+
+        assert!(!history.undo(&mut document));
+        assert!(!history.redo(&mut document));
+    }
+}
@@ -0,0 +1,108 @@
+//! A typestate builder for [`Config`]: the type parameters track which
+//! required fields have been set, so `build()` is only callable once both
+//! are present — forgetting one is a compile error, not a runtime panic.
+
+use std::marker::PhantomData;
+
+pub struct Config {
+    pub url: String,
+    pub key: String,
+    pub timeout_ms: u64,
+}
+
+/// Typestate marker: the field has not been set yet.
+pub struct Missing;
+/// Typestate marker: the field has been set.
+pub struct Set;
+
+pub struct ConfigBuilder<UrlState, KeyState> {
+    url: Option<String>,
+    key: Option<String>,
+    timeout_ms: u64,
+    _url_state: PhantomData<UrlState>,
+    _key_state: PhantomData<KeyState>,
+}
+
+impl ConfigBuilder<Missing, Missing> {
+    pub fn new() -> Self {
+        ConfigBuilder {
+            url: None,
+            key: None,
+            timeout_ms: 30_000,
+            _url_state: PhantomData,
+            _key_state: PhantomData,
+        }
+    }
+}
+
+impl Default for ConfigBuilder<Missing, Missing> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<KeyState> ConfigBuilder<Missing, KeyState> {
+    pub fn url(self, url: impl Into<String>) -> ConfigBuilder<Set, KeyState> {
+        ConfigBuilder {
+            url: Some(url.into()),
+            key: self.key,
+            timeout_ms: self.timeout_ms,
+            _url_state: PhantomData,
+            _key_state: PhantomData,
+        }
+    }
+}
+
+impl<UrlState> ConfigBuilder<UrlState, Missing> {
+    pub fn key(self, key: impl Into<String>) -> ConfigBuilder<UrlState, Set> {
+        ConfigBuilder {
+            url: self.url,
+            key: Some(key.into()),
+            timeout_ms: self.timeout_ms,
+            _url_state: PhantomData,
+            _key_state: PhantomData,
+        }
+    }
+}
+
+impl<UrlState, KeyState> ConfigBuilder<UrlState, KeyState> {
+    pub fn timeout_ms(mut self, timeout_ms: u64) -> Self {
+        self.timeout_ms = timeout_ms;
+        self
+    }
+}
+
+impl ConfigBuilder<Set, Set> {
+    /// Only callable once both `url` and `key` have been provided.
+    pub fn build(self) -> Config {
+        Config {
+            url: self.url.expect("Set state guarantees url is present"),
+            key: self.key.expect("Set state guarantees key is present"),
+            timeout_ms: self.timeout_ms,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_config_once_required_fields_are_set() {
+        let config = ConfigBuilder::new()
+            .url("https://example.com")
+            .key("secret")
+            .timeout_ms(5_000)
+            .build();
+
+        assert_eq!(config.url, "https://example.com");
+        assert_eq!(config.key, "secret");
+        assert_eq!(config.timeout_ms, 5_000);
+    }
+
+    #[test]
+    fn build_is_unavailable_without_required_fields() {
+        let t = trybuild::TestCases::new();
+        t.compile_fail("tests/compile_fail/config_missing_url.rs");
+    }
+}
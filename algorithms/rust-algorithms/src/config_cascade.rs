@@ -0,0 +1,43 @@
+//! Resolves a key across layered settings maps, letting callers stack
+//! sources like defaults, a config file, and environment overrides without
+//! merging them into a single owned map.
+
+use std::collections::HashMap;
+
+/// Looks up `key` in `layers`, highest-priority first, returning the
+/// first value found or `None` if no layer defines it.
+pub fn resolve<'a>(key: &str, layers: &[&'a HashMap<String, String>]) -> Option<&'a str> {
+    layers
+        .iter()
+        .find_map(|layer| layer.get(key).map(String::as_str))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_highest_priority_layer_defining_the_key_wins() {
+        let defaults: HashMap<String, String> =
+            [("host".to_string(), "localhost".to_string())].into();
+        let file: HashMap<String, String> = [
+            ("host".to_string(), "config-file-host".to_string()),
+            ("port".to_string(), "8080".to_string()),
+        ]
+        .into();
+        let env: HashMap<String, String> = [("host".to_string(), "env-host".to_string())].into();
+
+        let layers = [&env, &file, &defaults];
+
+        assert_eq!(resolve("host", &layers), Some("env-host"));
+        assert_eq!(resolve("port", &layers), Some("8080"));
+    }
+
+    #[test]
+    fn a_key_defined_in_no_layer_returns_none() {
+        let defaults: HashMap<String, String> = HashMap::new();
+        let layers = [&defaults];
+
+        assert_eq!(resolve("missing", &layers), None);
+    }
+}
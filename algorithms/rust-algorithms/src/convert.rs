@@ -0,0 +1,52 @@
+//! Common `Option`/`Result` conversions, pulled out of ad-hoc call sites so
+//! they read as named operations instead of repeated `match` blocks.
+
+/// Turns a `Vec` of `Option`s into an `Option` of `Vec`, or `None` if any
+/// element was `None`.
+pub fn transpose_vec<T>(v: Vec<Option<T>>) -> Option<Vec<T>> {
+    v.into_iter().collect()
+}
+
+/// Returns the first `Some` in the sequence, or `None` if every element
+/// was `None`.
+pub fn first_some<T>(opts: impl IntoIterator<Item = Option<T>>) -> Option<T> {
+    opts.into_iter().flatten().next()
+}
+
+/// Collapses a nested `Result<Result<T, E>, E>` into a single `Result<T, E>`.
+pub fn flatten_result<T, E>(r: Result<Result<T, E>, E>) -> Result<T, E> {
+    r.and_then(|inner| inner)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transpose_vec_succeeds_when_all_some() {
+        let input = vec![Some(1), Some(2), Some(3)];
+        assert_eq!(transpose_vec(input), Some(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn transpose_vec_is_none_if_any_element_is_none() {
+        let input = vec![Some(1), None, Some(3)];
+        assert_eq!(transpose_vec(input), None);
+    }
+
+    #[test]
+    fn first_some_returns_first_present_value() {
+        assert_eq!(first_some(vec![None, None, Some(5), Some(6)]), Some(5));
+        assert_eq!(first_some(Vec::<Option<i32>>::new()), None);
+    }
+
+    #[test]
+    fn flatten_result_collapses_nested_results() {
+        let ok: Result<Result<i32, &str>, &str> = Ok(Ok(1));
+        let inner_err: Result<Result<i32, &str>, &str> = Ok(Err("inner"));
+        let outer_err: Result<Result<i32, &str>, &str> = Err("outer");
+        assert_eq!(flatten_result(ok), Ok(1));
+        assert_eq!(flatten_result(inner_err), Err("inner"));
+        assert_eq!(flatten_result(outer_err), Err("outer"));
+    }
+}
@@ -0,0 +1,100 @@
+//! A copy-on-write, structurally-shared binary search tree. `insert`
+//! returns a new tree rather than mutating in place, path-copying only
+//! the nodes on the way to the new value and sharing every untouched
+//! subtree with the original tree via `Rc`.
+
+use std::cmp::Ordering;
+use std::rc::Rc;
+
+struct Node<T> {
+    value: T,
+    left: Option<Rc<Node<T>>>,
+    right: Option<Rc<Node<T>>>,
+}
+
+#[derive(Clone)]
+pub struct CowTree<T> {
+    root: Option<Rc<Node<T>>>,
+}
+
+impl<T: Ord + Clone> CowTree<T> {
+    pub fn new() -> Self {
+        CowTree { root: None }
+    }
+
+    /// Returns a new tree with `value` inserted, leaving `self` unchanged.
+    pub fn insert(&self, value: T) -> CowTree<T> {
+        CowTree {
+            root: Some(insert_node(self.root.as_ref(), value)),
+        }
+    }
+
+    pub fn contains(&self, value: &T) -> bool {
+        let mut node = self.root.as_deref();
+        while let Some(current) = node {
+            node = match value.cmp(&current.value) {
+                Ordering::Equal => return true,
+                Ordering::Less => current.left.as_deref(),
+                Ordering::Greater => current.right.as_deref(),
+            };
+        }
+        false
+    }
+}
+
+impl<T: Ord + Clone> Default for CowTree<T> {
+    fn default() -> Self {
+        CowTree::new()
+    }
+}
+
+fn insert_node<T: Ord + Clone>(node: Option<&Rc<Node<T>>>, value: T) -> Rc<Node<T>> {
+    match node {
+        None => Rc::new(Node {
+            value,
+            left: None,
+            right: None,
+        }),
+        Some(node) => match value.cmp(&node.value) {
+            Ordering::Less => Rc::new(Node {
+                value: node.value.clone(),
+                left: Some(insert_node(node.left.as_ref(), value)),
+                right: node.right.clone(),
+            }),
+            Ordering::Greater => Rc::new(Node {
+                value: node.value.clone(),
+                left: node.left.clone(),
+                right: Some(insert_node(node.right.as_ref(), value)),
+            }),
+            Ordering::Equal => Rc::clone(node),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_leaves_the_base_tree_unchanged() {
+        let base = CowTree::new().insert(5).insert(3).insert(8);
+        let updated = base.insert(1);
+
+        assert!(!base.contains(&1));
+        assert!(updated.contains(&1));
+        assert!(updated.contains(&5) && updated.contains(&3) && updated.contains(&8));
+    }
+
+    #[test]
+    fn unchanged_subtrees_are_shared_between_versions() {
+        let base = CowTree::new().insert(5).insert(3).insert(8);
+        // The right subtree (rooted at 8) doesn't lie on the path to 1,
+        // so it should be reused, not copied.
+        let updated = base.insert(1);
+
+        let base_right = base.root.as_ref().unwrap().right.as_ref().unwrap();
+        let updated_right = updated.root.as_ref().unwrap().right.as_ref().unwrap();
+        assert!(Rc::ptr_eq(base_right, updated_right));
+        assert!(Rc::strong_count(base_right) > 1);
+    }
+}
@@ -0,0 +1,112 @@
+//! Coalesces bursts of `trigger()` calls into a single action, firing only
+//! once the caller has been quiet for a configured period. Reuses
+//! [`crate::rate_limiter::Clock`] so tests can drive time manually instead
+//! of sleeping.
+
+use std::time::{Duration, Instant};
+
+use crate::rate_limiter::{Clock, SystemClock};
+
+/// Fires `action` at most once per quiet period, resetting the wait
+/// whenever [`trigger`](Debouncer::trigger) is called again before it
+/// fires.
+pub struct Debouncer<C: Clock, F: FnMut()> {
+    quiet_period: Duration,
+    clock: C,
+    action: F,
+    last_trigger: Option<Instant>,
+    fired: bool,
+}
+
+impl<F: FnMut()> Debouncer<SystemClock, F> {
+    pub fn new(quiet_period: Duration, action: F) -> Self {
+        Debouncer::with_clock(quiet_period, SystemClock, action)
+    }
+}
+
+impl<C: Clock, F: FnMut()> Debouncer<C, F> {
+    pub fn with_clock(quiet_period: Duration, clock: C, action: F) -> Self {
+        Debouncer {
+            quiet_period,
+            clock,
+            action,
+            last_trigger: None,
+            fired: false,
+        }
+    }
+
+    /// Records an event, restarting the quiet period.
+    pub fn trigger(&mut self) {
+        self.last_trigger = Some(self.clock.now());
+        self.fired = false;
+    }
+
+    /// Checks whether the quiet period has elapsed since the last
+    /// `trigger()` and, if so, invokes the action exactly once.
+    pub fn poll(&mut self) {
+        if self.fired {
+            return;
+        }
+        if let Some(last) = self.last_trigger {
+            if self.clock.now().duration_since(last) >= self.quiet_period {
+                (self.action)();
+                self.fired = true;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    struct MockClock {
+        now: Cell<Instant>,
+    }
+
+    impl MockClock {
+        fn new() -> Self {
+            MockClock {
+                now: Cell::new(Instant::now()),
+            }
+        }
+
+        fn advance(&self, by: Duration) {
+            self.now.set(self.now.get() + by);
+        }
+    }
+
+    impl Clock for &MockClock {
+        fn now(&self) -> Instant {
+            self.now.get()
+        }
+    }
+
+    #[test]
+    fn three_rapid_triggers_coalesce_into_a_single_call_once_the_window_passes() {
+        let clock = MockClock::new();
+        let calls = Cell::new(0);
+        let mut debouncer =
+            Debouncer::with_clock(Duration::from_millis(100), &clock, || calls.set(calls.get() + 1));
+
+        debouncer.trigger();
+        debouncer.poll();
+        clock.advance(Duration::from_millis(10));
+
+        debouncer.trigger();
+        debouncer.poll();
+        clock.advance(Duration::from_millis(10));
+
+        debouncer.trigger();
+        debouncer.poll();
+        assert_eq!(calls.get(), 0);
+
+        clock.advance(Duration::from_millis(101));
+        debouncer.poll();
+        assert_eq!(calls.get(), 1);
+
+        debouncer.poll();
+        assert_eq!(calls.get(), 1);
+    }
+}
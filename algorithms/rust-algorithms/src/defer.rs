@@ -0,0 +1,81 @@
+//! An RAII scope guard that runs a closure on drop, so cleanup code runs
+//! on every exit path out of a scope — early return, `?`, or an unwinding
+//! panic — not just the one the author had in mind.
+
+/// Runs `f` exactly once, when the guard is dropped.
+pub struct Defer<F: FnMut()> {
+    f: F,
+}
+
+impl<F: FnMut()> Defer<F> {
+    pub fn new(f: F) -> Self {
+        Defer { f }
+    }
+}
+
+impl<F: FnMut()> Drop for Defer<F> {
+    fn drop(&mut self) {
+        (self.f)();
+    }
+}
+
+/// Runs the given statements when the enclosing scope exits, via a
+/// [`Defer`] bound to an underscore-prefixed local so it isn't flagged
+/// as unused.
+///
+/// `#[macro_export]` puts this at the crate root (`crate::defer!`),
+/// matching how [`crate::builder`] is exported.
+#[macro_export]
+macro_rules! defer {
+    ($($body:tt)*) => {
+        let _guard = $crate::defer::Defer::new(|| { $($body)* });
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn the_closure_runs_exactly_once_on_scope_exit() {
+        let counter = AtomicUsize::new(0);
+        {
+            let _guard = Defer::new(|| {
+                counter.fetch_add(1, Ordering::SeqCst);
+            });
+        }
+        assert_eq!(counter.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn defer_macro_runs_cleanup_on_early_return() {
+        let counter = AtomicUsize::new(0);
+
+        fn returns_early(counter: &AtomicUsize, bail: bool) {
+            crate::defer!(counter.fetch_add(1, Ordering::SeqCst););
+            if bail {
+                return;
+            }
+            counter.fetch_add(100, Ordering::SeqCst);
+        }
+
+        returns_early(&counter, true);
+        assert_eq!(counter.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn the_guard_still_runs_while_unwinding_from_a_panic() {
+        let counter = AtomicUsize::new(0);
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            let _guard = Defer::new(|| {
+                counter.fetch_add(1, Ordering::SeqCst);
+            });
+            panic!("boom");
+        }));
+
+        assert!(result.is_err());
+        assert_eq!(counter.load(Ordering::SeqCst), 1);
+    }
+}
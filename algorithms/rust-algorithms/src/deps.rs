@@ -0,0 +1,184 @@
+//! Dependency-graph utilities for a build-system-style example, where
+//! modules are named by `String` rather than addressed by node handle
+//! (see [`crate::graph`] for the handle-based graph used elsewhere).
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+/// Finds directed cycles in `deps`, where `deps[name]` lists the modules
+/// `name` depends on, reporting one cycle for each edge a depth-first
+/// search finds back into its current path. Each cycle is reported as an
+/// ordered node list starting from the node where the cycle was first
+/// detected. A cycle that closes through a node whose search has already
+/// finished is not reported, so the result can omit some cycles of the
+/// graph; it is empty only if the graph is acyclic.
+pub fn detect_cycles(deps: &HashMap<String, Vec<String>>) -> Vec<Vec<String>> {
+    let mut cycles = Vec::new();
+    let mut visited = HashSet::new();
+
+    let mut names: Vec<&String> = deps.keys().collect();
+    names.sort();
+
+    for start in names {
+        if visited.contains(start) {
+            continue;
+        }
+        let mut stack = Vec::new();
+        let mut on_stack = HashSet::new();
+        find_cycles_from(start, deps, &mut visited, &mut stack, &mut on_stack, &mut cycles);
+    }
+
+    cycles
+}
+
+fn find_cycles_from(
+    node: &str,
+    deps: &HashMap<String, Vec<String>>,
+    visited: &mut HashSet<String>,
+    stack: &mut Vec<String>,
+    on_stack: &mut HashSet<String>,
+    cycles: &mut Vec<Vec<String>>,
+) {
+    stack.push(node.to_string());
+    on_stack.insert(node.to_string());
+
+    if let Some(neighbors) = deps.get(node) {
+        for neighbor in neighbors {
+            if let Some(start) = stack.iter().position(|n| n == neighbor) {
+                if on_stack.contains(neighbor) {
+                    cycles.push(stack[start..].to_vec());
+                }
+            } else if !visited.contains(neighbor) {
+                find_cycles_from(neighbor, deps, visited, stack, on_stack, cycles);
+            }
+        }
+    }
+
+    stack.pop();
+    on_stack.remove(node);
+    visited.insert(node.to_string());
+}
+
+/// Errors returned by [`build_order`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BuildError {
+    /// The dependency graph contains at least one cycle.
+    CycleDetected(Vec<Vec<String>>),
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BuildError::CycleDetected(cycles) => write!(f, "dependency graph has {} cycle(s)", cycles.len()),
+        }
+    }
+}
+
+impl std::error::Error for BuildError {}
+
+/// Groups `deps` into build "waves": each wave is a batch of targets
+/// whose dependencies were all satisfied by earlier waves, so a parallel
+/// scheduler could run every target within a wave concurrently.
+pub fn build_order(deps: &HashMap<String, Vec<String>>) -> Result<Vec<Vec<String>>, BuildError> {
+    let cycles = detect_cycles(deps);
+    if !cycles.is_empty() {
+        return Err(BuildError::CycleDetected(cycles));
+    }
+
+    // `remaining[name]` counts name's unresolved dependencies; `dependents[dep]`
+    // lists the names that depend on `dep`, so finishing `dep` can decrement them.
+    let mut remaining: HashMap<&str, usize> = HashMap::new();
+    let mut dependents: HashMap<&str, Vec<&str>> = HashMap::new();
+    for (name, targets) in deps {
+        remaining.insert(name.as_str(), targets.len());
+        for target in targets {
+            dependents.entry(target.as_str()).or_default().push(name.as_str());
+        }
+    }
+
+    let mut waves = Vec::new();
+    while !remaining.is_empty() {
+        let mut ready: Vec<&str> = remaining
+            .iter()
+            .filter(|&(_, &degree)| degree == 0)
+            .map(|(&name, _)| name)
+            .collect();
+        ready.sort_unstable();
+
+        for &name in &ready {
+            remaining.remove(name);
+        }
+        for &name in &ready {
+            if let Some(names) = dependents.get(name) {
+                for &dependent in names {
+                    if let Some(degree) = remaining.get_mut(dependent) {
+                        *degree -= 1;
+                    }
+                }
+            }
+        }
+
+        waves.push(ready.into_iter().map(|name| name.to_string()).collect());
+    }
+
+    Ok(waves)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn deps_map(pairs: &[(&str, &[&str])]) -> HashMap<String, Vec<String>> {
+        pairs
+            .iter()
+            .map(|&(name, targets)| (name.to_string(), targets.iter().map(|t| t.to_string()).collect()))
+            .collect()
+    }
+
+    #[test]
+    fn a_three_node_cycle_is_reported_alongside_an_acyclic_branch() {
+        let deps = deps_map(&[
+            ("A", &["B"]),
+            ("B", &["C"]),
+            ("C", &["A"]),
+            ("D", &["E"]),
+            ("E", &[]),
+        ]);
+
+        let cycles = detect_cycles(&deps);
+        assert_eq!(cycles.len(), 1);
+        assert_eq!(cycles[0], vec!["A".to_string(), "B".to_string(), "C".to_string()]);
+    }
+
+    #[test]
+    fn an_acyclic_graph_reports_no_cycles() {
+        let deps = deps_map(&[("A", &["B"]), ("B", &["C"]), ("C", &[])]);
+        assert!(detect_cycles(&deps).is_empty());
+    }
+
+    #[test]
+    fn build_order_puts_dependencies_before_dependents_and_batches_independent_targets() {
+        // compile depends on nothing; link and docs both depend only on
+        // compile, so they should share a wave; package depends on both.
+        let deps = deps_map(&[
+            ("compile", &[]),
+            ("link", &["compile"]),
+            ("docs", &["compile"]),
+            ("package", &["link", "docs"]),
+        ]);
+
+        let waves = build_order(&deps).expect("acyclic graph has a build order");
+        let wave_of = |name: &str| waves.iter().position(|wave| wave.iter().any(|n| n == name)).unwrap();
+
+        assert!(wave_of("compile") < wave_of("link"));
+        assert!(wave_of("compile") < wave_of("docs"));
+        assert_eq!(wave_of("link"), wave_of("docs"));
+        assert!(wave_of("link") < wave_of("package"));
+    }
+
+    #[test]
+    fn build_order_reports_a_cycle_instead_of_an_order() {
+        let deps = deps_map(&[("A", &["B"]), ("B", &["A"])]);
+        assert!(matches!(build_order(&deps), Err(BuildError::CycleDetected(_))));
+    }
+}
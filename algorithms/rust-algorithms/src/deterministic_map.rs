@@ -0,0 +1,74 @@
+//! A map wrapper for tests and examples that print their contents:
+//! lookups go through a `HashMap` for speed, but iteration always walks
+//! keys in sorted order, so assertions on printed output stay stable
+//! instead of depending on `HashMap`'s randomized iteration order.
+
+use std::collections::HashMap;
+
+#[derive(Default)]
+pub struct DeterministicMap<K: Ord, V> {
+    entries: HashMap<K, V>,
+}
+
+impl<K: Ord + std::hash::Hash, V> DeterministicMap<K, V> {
+    pub fn new() -> Self {
+        DeterministicMap { entries: HashMap::new() }
+    }
+
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.entries.insert(key, value)
+    }
+
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.entries.get(key)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Iterates entries in ascending key order.
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        let mut keys: Vec<&K> = self.entries.keys().collect();
+        keys.sort();
+        keys.into_iter().map(move |key| (key, &self.entries[key]))
+    }
+
+    pub fn to_sorted_vec(&self) -> Vec<(K, V)>
+    where
+        K: Clone,
+        V: Clone,
+    {
+        self.iter().map(|(key, value)| (key.clone(), value.clone())).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn iteration_yields_keys_inserted_in_random_order_sorted() {
+        let mut map = DeterministicMap::new();
+        map.insert("banana", 2);
+        map.insert("apple", 1);
+        map.insert("cherry", 3);
+
+        let keys: Vec<&&str> = map.iter().map(|(key, _)| key).collect();
+        assert_eq!(keys, vec![&"apple", &"banana", &"cherry"]);
+    }
+
+    #[test]
+    fn to_sorted_vec_matches_manual_iteration() {
+        let mut map = DeterministicMap::new();
+        map.insert(3, "three");
+        map.insert(1, "one");
+        map.insert(2, "two");
+
+        assert_eq!(map.to_sorted_vec(), vec![(1, "one"), (2, "two"), (3, "three")]);
+    }
+}
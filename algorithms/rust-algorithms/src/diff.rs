@@ -0,0 +1,175 @@
+//! A longest-common-subsequence based diff, producing an edit script that
+//! can turn `old` into `new` one element at a time, and an `apply` that
+//! replays such a script.
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Edit<T> {
+    Keep(T),
+    Insert(T),
+    Delete(T),
+}
+
+/// Computes an LCS-based edit script turning `old` into `new`.
+pub fn diff<T: PartialEq + Clone>(old: &[T], new: &[T]) -> Vec<Edit<T>> {
+    let lengths = lcs_lengths(old, new);
+
+    let mut edits = Vec::new();
+    let mut i = old.len();
+    let mut j = new.len();
+    while i > 0 || j > 0 {
+        if i > 0 && j > 0 && old[i - 1] == new[j - 1] {
+            edits.push(Edit::Keep(old[i - 1].clone()));
+            i -= 1;
+            j -= 1;
+        } else if j > 0 && (i == 0 || lengths[i][j - 1] >= lengths[i - 1][j]) {
+            edits.push(Edit::Insert(new[j - 1].clone()));
+            j -= 1;
+        } else {
+            edits.push(Edit::Delete(old[i - 1].clone()));
+            i -= 1;
+        }
+    }
+    edits.reverse();
+    edits
+}
+
+/// An edit script referred to an element the base slice didn't actually
+/// have at that position, meaning it was not produced from this `base`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApplyError {
+    pub position: usize,
+}
+
+impl std::fmt::Display for ApplyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "edit script diverges from base at position {}",
+            self.position
+        )
+    }
+}
+
+impl std::error::Error for ApplyError {}
+
+/// Replays `edits` against `base`, reconstructing the target slice. Errors
+/// if a `Keep`/`Delete` doesn't match the next element of `base`, which
+/// means the script wasn't produced from this `base`.
+pub fn apply<T: Clone + PartialEq>(base: &[T], edits: &[Edit<T>]) -> Result<Vec<T>, ApplyError> {
+    let mut result = Vec::new();
+    let mut pos = 0;
+    for edit in edits {
+        match edit {
+            Edit::Keep(value) => {
+                if base.get(pos) != Some(value) {
+                    return Err(ApplyError { position: pos });
+                }
+                result.push(value.clone());
+                pos += 1;
+            }
+            Edit::Delete(value) => {
+                if base.get(pos) != Some(value) {
+                    return Err(ApplyError { position: pos });
+                }
+                pos += 1;
+            }
+            Edit::Insert(value) => {
+                result.push(value.clone());
+            }
+        }
+    }
+    Ok(result)
+}
+
+/// `lengths[i][j]` is the LCS length of `old[..i]` and `new[..j]`.
+fn lcs_lengths<T: PartialEq>(old: &[T], new: &[T]) -> Vec<Vec<usize>> {
+    let mut lengths = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in 1..=old.len() {
+        for j in 1..=new.len() {
+            lengths[i][j] = if old[i - 1] == new[j - 1] {
+                lengths[i - 1][j - 1] + 1
+            } else {
+                lengths[i - 1][j].max(lengths[i][j - 1])
+            };
+        }
+    }
+    lengths
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diff_reconstructs_new_when_applied_to_old() {
+        let old = ["a", "b", "c"];
+        let new = ["a", "c", "d"];
+
+        let edits = diff(&old, &new);
+
+        let reconstructed: Vec<&str> = edits
+            .iter()
+            .filter_map(|edit| match edit {
+                Edit::Keep(v) | Edit::Insert(v) => Some(*v),
+                Edit::Delete(_) => None,
+            })
+            .collect();
+        assert_eq!(reconstructed, new);
+    }
+
+    #[test]
+    fn diff_handles_empty_inputs() {
+        let empty: [&str; 0] = [];
+        let new = ["a", "b"];
+
+        assert_eq!(
+            diff(&empty, &new),
+            vec![Edit::Insert("a"), Edit::Insert("b")]
+        );
+        assert_eq!(
+            diff(&new, &empty),
+            vec![Edit::Delete("a"), Edit::Delete("b")]
+        );
+        assert!(diff::<&str>(&empty, &empty).is_empty());
+    }
+
+    #[test]
+    fn apply_replays_a_diff_back_to_new() {
+        let old = ["a", "b", "c"];
+        let new = ["a", "c", "d"];
+
+        let edits = diff(&old, &new);
+        let rebuilt = apply(&old, &edits).expect("script was produced from old");
+
+        assert_eq!(rebuilt, new);
+    }
+
+    #[test]
+    fn apply_rejects_a_script_that_diverges_from_base() {
+        let edits = vec![Edit::Delete("z")];
+        assert_eq!(apply(&["a"], &edits), Err(ApplyError { position: 0 }));
+    }
+
+    #[test]
+    fn diff_then_apply_round_trips_for_many_random_pairs() {
+        // Small xorshift so this test has no dependency on a `rand` crate.
+        let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+
+        for _ in 0..200 {
+            let old_len = (next() % 6) as usize;
+            let new_len = (next() % 6) as usize;
+            let old: Vec<u32> = (0..old_len).map(|_| (next() % 4) as u32).collect();
+            let new: Vec<u32> = (0..new_len).map(|_| (next() % 4) as u32).collect();
+
+            let edits = diff(&old, &new);
+            let rebuilt = apply(&old, &edits).expect("diff always produces a valid script");
+            assert_eq!(rebuilt, new);
+        }
+    }
+}
@@ -0,0 +1,91 @@
+//! Dimensional analysis via const generics: a value's length/time exponents
+//! live in its type, so adding a length to a time is a compile error rather
+//! than a unit bug caught at runtime.
+//!
+//! Stable Rust doesn't yet allow arithmetic on const generic parameters in
+//! an `impl`'s signature (e.g. `Dim<{ L1 + L2 }, { T1 + T2 }>`), so unlike
+//! [`crate::units`]'s single-unit conversions, `Mul`/`Div` here are given
+//! concrete, named dimension pairs (see [`Length`], [`Time`], [`Speed`])
+//! rather than being fully generic over every exponent combination.
+
+use std::ops::{Add, Div, Mul};
+
+/// A scalar value tagged with its length exponent `L` and time exponent
+/// `T`. `Dim<0, 0>` is dimensionless, `Dim<1, 0>` is a length, and so on.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Dim<const L: i8, const T: i8> {
+    value: f64,
+}
+
+impl<const L: i8, const T: i8> Dim<L, T> {
+    pub fn new(value: f64) -> Self {
+        Dim { value }
+    }
+
+    pub fn value(self) -> f64 {
+        self.value
+    }
+}
+
+/// Adding requires identical dimensions on both sides, since `Self` fixes
+/// `L` and `T` to the same values for both operands.
+impl<const L: i8, const T: i8> Add for Dim<L, T> {
+    type Output = Dim<L, T>;
+
+    fn add(self, other: Dim<L, T>) -> Dim<L, T> {
+        Dim::new(self.value + other.value)
+    }
+}
+
+pub type Scalar = Dim<0, 0>;
+pub type Length = Dim<1, 0>;
+pub type Time = Dim<0, 1>;
+pub type Speed = Dim<1, -1>;
+
+impl Div<Time> for Length {
+    type Output = Speed;
+
+    fn div(self, time: Time) -> Speed {
+        Dim::new(self.value / time.value)
+    }
+}
+
+impl Mul<Time> for Speed {
+    type Output = Length;
+
+    fn mul(self, time: Time) -> Length {
+        Dim::new(self.value * time.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn accepts_speed(_: Speed) {}
+
+    #[test]
+    fn dividing_a_length_by_a_time_yields_a_speed() {
+        let distance = Length::new(10.0);
+        let elapsed = Time::new(2.0);
+
+        let speed = distance / elapsed;
+        accepts_speed(speed);
+
+        assert_eq!(speed.value(), 5.0);
+    }
+
+    #[test]
+    fn multiplying_a_speed_by_a_time_yields_a_length_again() {
+        let speed = Speed::new(5.0);
+        let elapsed = Time::new(2.0);
+
+        assert_eq!((speed * elapsed).value(), 10.0);
+    }
+
+    #[test]
+    fn adding_length_to_time_does_not_compile() {
+        let t = trybuild::TestCases::new();
+        t.compile_fail("tests/compile_fail/dim_incompatible_add.rs");
+    }
+}
@@ -0,0 +1,159 @@
+//! A minimal in-memory document, indexed by a [`Trie`] so prefix search
+//! doesn't need to scan every word. [`DocumentBuilder`] lets callers add
+//! optional metadata and choose whether title/content are borrowed or
+//! owned.
+
+use std::borrow::Cow;
+
+use crate::strings::{char_count, truncate_chars};
+use crate::trie::Trie;
+
+pub struct Document<'a> {
+    title: Cow<'a, str>,
+    content: Cow<'a, str>,
+    tags: Vec<String>,
+    author: Option<String>,
+    index: Trie,
+}
+
+impl<'a> Document<'a> {
+    pub fn new(title: impl Into<Cow<'a, str>>, content: impl Into<Cow<'a, str>>) -> Self {
+        DocumentBuilder::new().title(title).content(content).build()
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+
+    pub fn tags(&self) -> &[String] {
+        &self.tags
+    }
+
+    pub fn author(&self) -> Option<&str> {
+        self.author.as_deref()
+    }
+
+    /// Returns every distinct word in the document starting with `prefix`.
+    pub fn find_words_starting_with(&self, prefix: &str) -> Vec<String> {
+        self.index.words_with_prefix(prefix)
+    }
+
+    /// Counts words by whitespace splitting, same as the indexing pass.
+    pub fn word_count(&self) -> usize {
+        self.content.split_whitespace().count()
+    }
+
+    /// Returns the first `max_chars` characters of the content, safely
+    /// truncated at a `char` boundary rather than a byte offset.
+    pub fn get_summary(&self, max_chars: usize) -> &str {
+        truncate_chars(&self.content, max_chars)
+    }
+
+    /// Counts the characters (not bytes) in the content.
+    pub fn char_count(&self) -> usize {
+        char_count(&self.content)
+    }
+}
+
+fn index_words(content: &str) -> Trie {
+    let mut index = Trie::new();
+    for word in content.split_whitespace() {
+        index.insert(word);
+    }
+    index
+}
+
+/// Builds a [`Document`], filling in optional metadata before construction
+/// so `Document::new` can stay a two-argument convenience constructor.
+#[derive(Default)]
+pub struct DocumentBuilder<'a> {
+    title: Option<Cow<'a, str>>,
+    content: Option<Cow<'a, str>>,
+    tags: Vec<String>,
+    author: Option<String>,
+}
+
+impl<'a> DocumentBuilder<'a> {
+    pub fn new() -> Self {
+        DocumentBuilder::default()
+    }
+
+    pub fn title(mut self, title: impl Into<Cow<'a, str>>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    pub fn content(mut self, content: impl Into<Cow<'a, str>>) -> Self {
+        self.content = Some(content.into());
+        self
+    }
+
+    pub fn tag(mut self, tag: impl Into<String>) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    pub fn author(mut self, author: impl Into<String>) -> Self {
+        self.author = Some(author.into());
+        self
+    }
+
+    /// Builds the document, defaulting title/content to empty strings if
+    /// they weren't set.
+    pub fn build(self) -> Document<'a> {
+        let title = self.title.unwrap_or(Cow::Borrowed(""));
+        let content = self.content.unwrap_or(Cow::Borrowed(""));
+        let index = index_words(&content);
+        Document {
+            title,
+            content,
+            tags: self.tags,
+            author: self.author,
+            index,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_words_by_prefix_via_the_trie_index() {
+        let doc = Document::new("notes", "world wonderful hello");
+
+        let mut matches = doc.find_words_starting_with("wo");
+        matches.sort();
+        assert_eq!(matches, vec!["wonderful", "world"]);
+        assert!(doc.find_words_starting_with("x").is_empty());
+    }
+
+    #[test]
+    fn get_summary_truncates_by_character_without_panicking_on_multi_byte_input() {
+        let doc = Document::new("notes", "a🦀bc");
+
+        assert_eq!(doc.get_summary(2), "a🦀");
+        assert_eq!(doc.char_count(), 4);
+    }
+
+    #[test]
+    fn builder_attaches_tags_and_author() {
+        let owned_title = String::from("Report");
+        let doc = DocumentBuilder::new()
+            .title(owned_title) // owned String coerces into Cow::Owned
+            .content("borrowed content") // &'static str coerces into Cow::Borrowed
+            .tag("draft")
+            .tag("q3")
+            .author("ada")
+            .build();
+
+        assert_eq!(doc.title(), "Report");
+        assert_eq!(doc.content(), "borrowed content");
+        assert_eq!(doc.tags(), &["draft".to_string(), "q3".to_string()]);
+        assert_eq!(doc.author(), Some("ada"));
+    }
+}
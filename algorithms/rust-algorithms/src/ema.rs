@@ -0,0 +1,74 @@
+//! An exponential moving average, complementing the rolling statistics
+//! elsewhere in this crate with a smoothed running estimate that weights
+//! recent values more heavily.
+
+use std::fmt;
+
+/// Returned when constructing an [`Ema`] with a smoothing factor outside
+/// the valid range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidAlpha;
+
+impl fmt::Display for InvalidAlpha {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "alpha must be in (0, 1]")
+    }
+}
+
+impl std::error::Error for InvalidAlpha {}
+
+pub struct Ema {
+    alpha: f64,
+    value: Option<f64>,
+}
+
+impl Ema {
+    /// `alpha` controls how quickly the average tracks new values: closer
+    /// to 1 weights recent samples more heavily, closer to 0 smooths more.
+    pub fn new(alpha: f64) -> Result<Self, InvalidAlpha> {
+        if alpha <= 0.0 || alpha > 1.0 {
+            return Err(InvalidAlpha);
+        }
+        Ok(Ema { alpha, value: None })
+    }
+
+    /// Folds `x` into the average, seeding it directly on the first call.
+    pub fn update(&mut self, x: f64) -> f64 {
+        let updated = match self.value {
+            Some(previous) => self.alpha * x + (1.0 - self.alpha) * previous,
+            None => x,
+        };
+        self.value = Some(updated);
+        updated
+    }
+
+    pub fn get(&self) -> Option<f64> {
+        self.value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_constant_series_converges_to_that_constant() {
+        let mut ema = Ema::new(0.3).unwrap();
+        for _ in 0..50 {
+            ema.update(10.0);
+        }
+        assert!((ema.get().unwrap() - 10.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn the_first_update_seeds_the_value_directly() {
+        let mut ema = Ema::new(0.5).unwrap();
+        assert_eq!(ema.update(4.0), 4.0);
+    }
+
+    #[test]
+    fn an_out_of_range_alpha_is_rejected_at_construction() {
+        assert!(Ema::new(0.0).is_err());
+        assert!(Ema::new(1.5).is_err());
+    }
+}
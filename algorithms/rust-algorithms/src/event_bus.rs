@@ -0,0 +1,104 @@
+//! A generalization of the observer pattern: instead of one `Subject` per
+//! piece of state, an `EventBus` routes any `'static` event type to the
+//! handlers subscribed for it, keyed by `TypeId`.
+
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+
+type Handler<E> = Box<dyn Fn(&E)>;
+
+/// Type-erases a `Vec<Handler<E>>` so handlers for different event types
+/// can live in the same map.
+trait ErasedHandlers {
+    fn as_any(&self) -> &dyn Any;
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+struct HandlerList<E> {
+    handlers: Vec<Handler<E>>,
+}
+
+impl<E: 'static> ErasedHandlers for HandlerList<E> {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+/// Routes published events to every handler subscribed for that event's
+/// concrete type.
+#[derive(Default)]
+pub struct EventBus {
+    handlers: HashMap<TypeId, Box<dyn ErasedHandlers>>,
+}
+
+impl EventBus {
+    pub fn new() -> Self {
+        EventBus::default()
+    }
+
+    /// Registers `handler` to run whenever an `E` is published.
+    pub fn subscribe<E: 'static>(&mut self, handler: impl Fn(&E) + 'static) {
+        let list = self
+            .handlers
+            .entry(TypeId::of::<E>())
+            .or_insert_with(|| Box::new(HandlerList::<E> { handlers: Vec::new() }))
+            .as_any_mut()
+            .downcast_mut::<HandlerList<E>>()
+            .expect("handler list type matches TypeId key");
+        list.handlers.push(Box::new(handler));
+    }
+
+    /// Runs every handler subscribed for `E`'s type against `event`.
+    pub fn publish<E: 'static>(&self, event: E) {
+        if let Some(list) = self.handlers.get(&TypeId::of::<E>()) {
+            let list = list
+                .as_any()
+                .downcast_ref::<HandlerList<E>>()
+                .expect("handler list type matches TypeId key");
+            for handler in &list.handlers {
+                handler(&event);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    struct Login {
+        user: String,
+    }
+
+    struct Logout {
+        user: String,
+    }
+
+    #[test]
+    fn only_matching_handlers_fire() {
+        let logins = Rc::new(RefCell::new(Vec::new()));
+        let logouts = Rc::new(RefCell::new(Vec::new()));
+
+        let mut bus = EventBus::new();
+        let login_log = Rc::clone(&logins);
+        bus.subscribe::<Login>(move |event| login_log.borrow_mut().push(event.user.clone()));
+        let logout_log = Rc::clone(&logouts);
+        bus.subscribe::<Logout>(move |event| logout_log.borrow_mut().push(event.user.clone()));
+
+        bus.publish(Login {
+            user: "ada".to_string(),
+        });
+        bus.publish(Logout {
+            user: "ada".to_string(),
+        });
+
+        assert_eq!(*logins.borrow(), vec!["ada".to_string()]);
+        assert_eq!(*logouts.borrow(), vec!["ada".to_string()]);
+    }
+}
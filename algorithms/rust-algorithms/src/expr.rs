@@ -0,0 +1,514 @@
+//! A tiny arithmetic expression language: lexer, recursive-descent parser,
+//! and an AST that traversals can drive through a [`Visitor`].
+
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::pretty::{pad, PrettyPrint};
+
+/// A half-open byte range into the source text that a token or error
+/// corresponds to.
+pub type Span = (usize, usize);
+
+/// An error produced while lexing or parsing, with enough context to point
+/// back at the offending source text.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    pub message: String,
+    pub span: Span,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for Diagnostic {}
+
+impl Diagnostic {
+    /// Renders the offending source line with a caret underline beneath
+    /// the diagnostic's span, followed by the message. The underline is
+    /// positioned by character column, not byte offset, so it still lines
+    /// up when the line contains multi-byte characters.
+    pub fn render(&self, source: &str) -> String {
+        let (line_start, line_end) = line_bounds(source, self.span.0);
+        let line = &source[line_start..line_end];
+
+        let start_byte = self.span.0 - line_start;
+        let end_byte = (self.span.1 - line_start).min(line.len());
+        let underline_start = byte_to_char_col(line, start_byte);
+        let underline_len = byte_to_char_col(line, end_byte)
+            .saturating_sub(underline_start)
+            .max(1);
+        let underline = " ".repeat(underline_start) + &"^".repeat(underline_len);
+
+        format!("{line}\n{underline}\n{}", self.message)
+    }
+}
+
+/// Finds the `[start, end)` byte range of the line containing `pos`.
+fn line_bounds(source: &str, pos: usize) -> (usize, usize) {
+    let line_start = source[..pos].rfind('\n').map(|i| i + 1).unwrap_or(0);
+    let line_end = source[pos..].find('\n').map(|i| pos + i).unwrap_or(source.len());
+    (line_start, line_end)
+}
+
+/// Converts a byte index within `line` to a character column, so
+/// diagnostics report the column a user actually sees rather than a raw
+/// byte offset, which is wrong once a multi-byte character precedes it.
+pub fn byte_to_char_col(line: &str, byte_idx: usize) -> usize {
+    line[..byte_idx].chars().count()
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Token {
+    Number(f64),
+    Ident(String),
+    Plus,
+    Minus,
+    Star,
+    Slash,
+    LParen,
+    RParen,
+    Eof,
+}
+
+pub(crate) fn tokenize(source: &str) -> Result<Vec<(Token, Span)>, Diagnostic> {
+    let char_at = |i: usize| source[i..].chars().next();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while let Some(c) = char_at(i) {
+        match c {
+            ' ' | '\t' | '\n' | '\r' => i += c.len_utf8(),
+            '+' => {
+                tokens.push((Token::Plus, (i, i + 1)));
+                i += 1;
+            }
+            '-' => {
+                tokens.push((Token::Minus, (i, i + 1)));
+                i += 1;
+            }
+            '*' => {
+                tokens.push((Token::Star, (i, i + 1)));
+                i += 1;
+            }
+            '/' => {
+                tokens.push((Token::Slash, (i, i + 1)));
+                i += 1;
+            }
+            '(' => {
+                tokens.push((Token::LParen, (i, i + 1)));
+                i += 1;
+            }
+            ')' => {
+                tokens.push((Token::RParen, (i, i + 1)));
+                i += 1;
+            }
+            c if c.is_ascii_digit() || c == '.' => {
+                let start = i;
+                while matches!(char_at(i), Some(c) if c.is_ascii_digit() || c == '.') {
+                    i += 1;
+                }
+                let text = &source[start..i];
+                let value = text.parse::<f64>().map_err(|_| Diagnostic {
+                    message: format!("invalid number literal `{text}`"),
+                    span: (start, i),
+                })?;
+                tokens.push((Token::Number(value), (start, i)));
+            }
+            c if c.is_ascii_alphabetic() || c == '_' => {
+                let start = i;
+                while matches!(char_at(i), Some(c) if c.is_ascii_alphanumeric() || c == '_') {
+                    i += 1;
+                }
+                tokens.push((Token::Ident(source[start..i].to_string()), (start, i)));
+            }
+            other => {
+                let end = i + other.len_utf8();
+                return Err(Diagnostic {
+                    message: format!("unexpected character `{other}`"),
+                    span: (i, end),
+                });
+            }
+        }
+    }
+    tokens.push((Token::Eof, (source.len(), source.len())));
+    Ok(tokens)
+}
+
+/// A binary arithmetic operator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+impl BinOp {
+    fn symbol(self) -> &'static str {
+        match self {
+            BinOp::Add => "+",
+            BinOp::Sub => "-",
+            BinOp::Mul => "*",
+            BinOp::Div => "/",
+        }
+    }
+}
+
+/// The arithmetic expression AST.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    Number(f64),
+    Variable(String),
+    BinaryOp {
+        op: BinOp,
+        left: Box<Expr>,
+        right: Box<Expr>,
+    },
+}
+
+impl PrettyPrint for Expr {
+    fn pretty(&self, indent: usize) -> String {
+        match self {
+            Expr::Number(value) => format!("{}{value}", pad(indent)),
+            Expr::Variable(name) => format!("{}{name}", pad(indent)),
+            Expr::BinaryOp { op, left, right } => {
+                let mut lines = vec![format!("{}{}", pad(indent), op.symbol())];
+                lines.push(left.pretty(indent + 1));
+                lines.push(right.pretty(indent + 1));
+                lines.join("\n")
+            }
+        }
+    }
+}
+
+struct Parser {
+    tokens: Vec<(Token, Span)>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> &Token {
+        &self.tokens[self.pos].0
+    }
+
+    fn peek_span(&self) -> Span {
+        self.tokens[self.pos].1
+    }
+
+    fn advance(&mut self) -> Token {
+        let token = self.tokens[self.pos].0.clone();
+        if self.pos + 1 < self.tokens.len() {
+            self.pos += 1;
+        }
+        token
+    }
+
+    /// `expr := term (('+' | '-') term)*`
+    fn parse_expr(&mut self) -> Result<Expr, Diagnostic> {
+        let mut left = self.parse_term()?;
+        loop {
+            let op = match self.peek() {
+                Token::Plus => BinOp::Add,
+                Token::Minus => BinOp::Sub,
+                _ => break,
+            };
+            self.advance();
+            let right = self.parse_term()?;
+            left = Expr::BinaryOp {
+                op,
+                left: Box::new(left),
+                right: Box::new(right),
+            };
+        }
+        Ok(left)
+    }
+
+    /// `term := factor (('*' | '/') factor)*`
+    fn parse_term(&mut self) -> Result<Expr, Diagnostic> {
+        let mut left = self.parse_factor()?;
+        loop {
+            let op = match self.peek() {
+                Token::Star => BinOp::Mul,
+                Token::Slash => BinOp::Div,
+                _ => break,
+            };
+            self.advance();
+            let right = self.parse_factor()?;
+            left = Expr::BinaryOp {
+                op,
+                left: Box::new(left),
+                right: Box::new(right),
+            };
+        }
+        Ok(left)
+    }
+
+    /// `factor := NUMBER | IDENT | '(' expr ')'`
+    fn parse_factor(&mut self) -> Result<Expr, Diagnostic> {
+        let span = self.peek_span();
+        match self.advance() {
+            Token::Number(value) => Ok(Expr::Number(value)),
+            Token::Ident(name) => Ok(Expr::Variable(name)),
+            Token::LParen => {
+                let inner = self.parse_expr()?;
+                match self.advance() {
+                    Token::RParen => Ok(inner),
+                    _ => Err(Diagnostic {
+                        message: "expected closing `)`".to_string(),
+                        span: self.peek_span(),
+                    }),
+                }
+            }
+            _ => Err(Diagnostic {
+                message: "expected a number, identifier, or `(`".to_string(),
+                span,
+            }),
+        }
+    }
+}
+
+/// Parses `source` into an [`Expr`], failing with a [`Diagnostic`] pointing
+/// at the offending span.
+pub fn parse(source: &str) -> Result<Expr, Diagnostic> {
+    let tokens = tokenize(source)?;
+    let mut parser = Parser { tokens, pos: 0 };
+    let expr = parser.parse_expr()?;
+    match parser.peek() {
+        Token::Eof => Ok(expr),
+        _ => Err(Diagnostic {
+            message: "unexpected trailing input".to_string(),
+            span: parser.peek_span(),
+        }),
+    }
+}
+
+/// Drives a traversal of an [`Expr`] tree, dispatching to one method per
+/// node kind. Implementors decide what to do at each node; [`walk`] handles
+/// recursing into children.
+pub trait Visitor<R> {
+    fn visit_number(&mut self, value: f64) -> R;
+    fn visit_variable(&mut self, name: &str) -> R;
+    fn visit_binary_op(&mut self, op: BinOp, left: &Expr, right: &Expr) -> R;
+}
+
+/// Dispatches `expr` to the matching [`Visitor`] method.
+pub fn walk<R>(visitor: &mut impl Visitor<R>, expr: &Expr) -> R {
+    match expr {
+        Expr::Number(value) => visitor.visit_number(*value),
+        Expr::Variable(name) => visitor.visit_variable(name),
+        Expr::BinaryOp { op, left, right } => visitor.visit_binary_op(*op, left, right),
+    }
+}
+
+/// A variable environment, mapping names to their `f64` values.
+pub type Environment = HashMap<String, f64>;
+
+/// An error raised while evaluating an [`Expr`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum EvalError {
+    UndefinedVariable(String),
+}
+
+impl fmt::Display for EvalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EvalError::UndefinedVariable(name) => write!(f, "undefined variable `{name}`"),
+        }
+    }
+}
+
+impl std::error::Error for EvalError {}
+
+/// Computes the `f64` value of an expression, resolving variables against
+/// an [`Environment`].
+pub struct Evaluator<'a> {
+    env: &'a Environment,
+}
+
+impl<'a> Evaluator<'a> {
+    pub fn new(env: &'a Environment) -> Self {
+        Evaluator { env }
+    }
+}
+
+impl Visitor<Result<f64, EvalError>> for Evaluator<'_> {
+    fn visit_number(&mut self, value: f64) -> Result<f64, EvalError> {
+        Ok(value)
+    }
+
+    fn visit_variable(&mut self, name: &str) -> Result<f64, EvalError> {
+        self.env
+            .get(name)
+            .copied()
+            .ok_or_else(|| EvalError::UndefinedVariable(name.to_string()))
+    }
+
+    fn visit_binary_op(&mut self, op: BinOp, left: &Expr, right: &Expr) -> Result<f64, EvalError> {
+        let left = walk(self, left)?;
+        let right = walk(self, right)?;
+        Ok(apply(op, left, right))
+    }
+}
+
+/// Renders an expression back to fully-parenthesized canonical source.
+#[derive(Default)]
+pub struct Printer;
+
+impl Visitor<String> for Printer {
+    fn visit_number(&mut self, value: f64) -> String {
+        format!("{value}")
+    }
+
+    fn visit_variable(&mut self, name: &str) -> String {
+        name.to_string()
+    }
+
+    fn visit_binary_op(&mut self, op: BinOp, left: &Expr, right: &Expr) -> String {
+        let left = walk(self, left);
+        let right = walk(self, right);
+        format!("({left} {} {right})", op.symbol())
+    }
+}
+
+/// Recursively replaces any `BinaryOp` whose children are both `Number`
+/// literals with the computed result. Leaves a division-by-zero node
+/// unfolded rather than panicking or producing `inf`/`NaN` silently.
+/// Variables are left untouched, so `x + 0` stays symbolic.
+pub fn fold_constants(expr: Expr) -> Expr {
+    match expr {
+        Expr::Number(value) => Expr::Number(value),
+        Expr::Variable(name) => Expr::Variable(name),
+        Expr::BinaryOp { op, left, right } => {
+            let left = fold_constants(*left);
+            let right = fold_constants(*right);
+            if let (Expr::Number(l), Expr::Number(r)) = (&left, &right) {
+                if !(op == BinOp::Div && *r == 0.0) {
+                    return Expr::Number(apply(op, *l, *r));
+                }
+            }
+            Expr::BinaryOp {
+                op,
+                left: Box::new(left),
+                right: Box::new(right),
+            }
+        }
+    }
+}
+
+fn apply(op: BinOp, left: f64, right: f64) -> f64 {
+    match op {
+        BinOp::Add => left + right,
+        BinOp::Sub => left - right,
+        BinOp::Mul => left * right,
+        BinOp::Div => left / right,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn printer_renders_canonical_parenthesized_source() {
+        let expr = parse("2+3*4").expect("valid expression");
+        let mut printer = Printer;
+        assert_eq!(walk(&mut printer, &expr), "(2 + (3 * 4))");
+    }
+
+    #[test]
+    fn evaluator_respects_operator_precedence() {
+        let expr = parse("2+3*4").expect("valid expression");
+        let env = Environment::new();
+        let mut evaluator = Evaluator::new(&env);
+        assert_eq!(walk(&mut evaluator, &expr), Ok(14.0));
+    }
+
+    #[test]
+    fn parentheses_override_precedence() {
+        let expr = parse("(2+3)*4").expect("valid expression");
+        let env = Environment::new();
+        let mut evaluator = Evaluator::new(&env);
+        assert_eq!(walk(&mut evaluator, &expr), Ok(20.0));
+    }
+
+    #[test]
+    fn pretty_prints_the_ast_with_one_operand_per_indented_line() {
+        let expr = parse("2+3*4").expect("valid expression");
+        assert_eq!(expr.pretty(0), "+\n  2\n  *\n    3\n    4");
+    }
+
+    #[test]
+    fn evaluator_resolves_variables_against_the_environment() {
+        let expr = parse("x * 2 + y").expect("valid expression");
+        let mut env = Environment::new();
+        env.insert("x".to_string(), 3.0);
+        env.insert("y".to_string(), 1.0);
+        let mut evaluator = Evaluator::new(&env);
+        assert_eq!(walk(&mut evaluator, &expr), Ok(7.0));
+    }
+
+    #[test]
+    fn evaluator_reports_undefined_variables() {
+        let expr = parse("x + 1").expect("valid expression");
+        let env = Environment::new();
+        let mut evaluator = Evaluator::new(&env);
+        assert_eq!(
+            walk(&mut evaluator, &expr),
+            Err(EvalError::UndefinedVariable("x".to_string()))
+        );
+    }
+
+    #[test]
+    fn fold_constants_collapses_to_a_single_number() {
+        let expr = parse("2 + 3 * 4").expect("valid expression");
+        assert_eq!(fold_constants(expr), Expr::Number(14.0));
+    }
+
+    #[test]
+    fn diagnostic_render_underlines_the_offending_token() {
+        let error = parse("2 + * 3").unwrap_err();
+        let rendered = error.render("2 + * 3");
+        let lines: Vec<&str> = rendered.lines().collect();
+        assert_eq!(lines[0], "2 + * 3");
+        assert_eq!(lines[1], "    ^");
+    }
+
+    #[test]
+    fn byte_to_char_col_reports_a_character_column_past_a_leading_emoji() {
+        let line = "🦀+1";
+        // The `+` starts right after the 4-byte crab emoji, at char
+        // column 1, not byte offset 4.
+        let plus_byte_offset = line.find('+').unwrap();
+        assert_eq!(byte_to_char_col(line, plus_byte_offset), 1);
+    }
+
+    #[test]
+    fn fold_constants_leaves_variables_symbolic() {
+        let expr = parse("x + 0").expect("valid expression");
+        assert_eq!(fold_constants(expr.clone()), expr);
+    }
+
+    #[test]
+    fn fold_constants_leaves_division_by_zero_unfolded() {
+        let expr = Expr::BinaryOp {
+            op: BinOp::Div,
+            left: Box::new(Expr::Number(1.0)),
+            right: Box::new(Expr::Number(0.0)),
+        };
+        assert_eq!(fold_constants(expr.clone()), expr);
+    }
+
+    #[test]
+    fn render_does_not_panic_on_an_unexpected_multi_byte_character() {
+        let source = "🦀+1";
+        let error = parse(source).unwrap_err();
+        let rendered = error.render(source);
+        let lines: Vec<&str> = rendered.lines().collect();
+        assert_eq!(lines[0], "🦀+1");
+        assert_eq!(lines[1], "^");
+    }
+}
@@ -0,0 +1,56 @@
+//! A Fenwick tree (binary indexed tree), complementing the static
+//! [`crate::prefix_sum::PrefixSum`] with O(log n) point updates.
+
+pub struct FenwickTree {
+    tree: Vec<i64>,
+}
+
+impl FenwickTree {
+    pub fn new(len: usize) -> Self {
+        FenwickTree {
+            tree: vec![0; len + 1],
+        }
+    }
+
+    /// Adds `delta` to `data[i]`, in O(log n).
+    pub fn update(&mut self, i: usize, delta: i64) {
+        let mut i = i + 1;
+        while i < self.tree.len() {
+            self.tree[i] += delta;
+            i += i & i.wrapping_neg();
+        }
+    }
+
+    /// The sum of `data[0..=i]`, in O(log n).
+    pub fn prefix_sum(&self, i: usize) -> i64 {
+        let mut i = i + 1;
+        let mut sum = 0;
+        while i > 0 {
+            sum += self.tree[i];
+            i -= i & i.wrapping_neg();
+        }
+        sum
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interleaved_updates_and_queries_match_a_naive_recomputation() {
+        let mut data = [0i64; 8];
+        let mut fenwick = FenwickTree::new(data.len());
+
+        let updates = [(0, 5), (3, 2), (7, -1), (3, 4), (5, 10)];
+        for (i, delta) in updates {
+            data[i] += delta;
+            fenwick.update(i, delta);
+
+            for j in 0..data.len() {
+                let expected: i64 = data[..=j].iter().sum();
+                assert_eq!(fenwick.prefix_sum(j), expected);
+            }
+        }
+    }
+}
@@ -0,0 +1,126 @@
+//! A fixed-point number backed by a scaled `i64`, avoiding the rounding
+//! surprises of `f64` for money-like arithmetic (e.g. `0.1 + 0.2 == 0.3`
+//! exactly).
+
+use std::ops::{Add, Mul, Sub};
+
+/// A value scaled by `10^DECIMALS` and stored as an `i64`. `Fixed<2>`
+/// represents cents, `Fixed<4>` represents ten-thousandths, and so on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Fixed<const DECIMALS: u32> {
+    scaled: i64,
+}
+
+impl<const DECIMALS: u32> Fixed<DECIMALS> {
+    fn scale() -> f64 {
+        10f64.powi(DECIMALS as i32)
+    }
+
+    /// Builds a `Fixed` directly from its already-scaled integer
+    /// representation.
+    pub fn from_scaled(scaled: i64) -> Self {
+        Fixed { scaled }
+    }
+
+    pub fn from_f64(value: f64) -> Self {
+        Fixed {
+            scaled: (value * Self::scale()).round() as i64,
+        }
+    }
+
+    pub fn to_f64(self) -> f64 {
+        self.scaled as f64 / Self::scale()
+    }
+
+    /// Multiplies two values of the same scale, returning `None` if the
+    /// rescaled product doesn't fit in `i64` (the intermediate product is
+    /// computed in `i128`). Rounds to the nearest
+    /// representable unit (half away from zero), the same way
+    /// [`Fixed::from_f64`] rounds, rather than truncating toward zero.
+    pub fn checked_mul(self, other: Fixed<DECIMALS>) -> Option<Fixed<DECIMALS>> {
+        let product = (self.scaled as i128) * (other.scaled as i128);
+        let scale = i128::from(Self::scale() as i64);
+        let half = scale / 2;
+        let rescaled = if product >= 0 {
+            (product + half) / scale
+        } else {
+            (product - half) / scale
+        };
+        i64::try_from(rescaled).ok().map(Fixed::from_scaled)
+    }
+}
+
+impl<const DECIMALS: u32> Mul for Fixed<DECIMALS> {
+    type Output = Fixed<DECIMALS>;
+
+    /// Multiplies two values, panicking on overflow. Use
+    /// [`Fixed::checked_mul`] to handle overflow explicitly.
+    fn mul(self, other: Fixed<DECIMALS>) -> Fixed<DECIMALS> {
+        self.checked_mul(other)
+            .expect("fixed-point multiplication overflowed")
+    }
+}
+
+impl<const DECIMALS: u32> Add for Fixed<DECIMALS> {
+    type Output = Fixed<DECIMALS>;
+
+    fn add(self, other: Fixed<DECIMALS>) -> Fixed<DECIMALS> {
+        Fixed::from_scaled(self.scaled + other.scaled)
+    }
+}
+
+impl<const DECIMALS: u32> Sub for Fixed<DECIMALS> {
+    type Output = Fixed<DECIMALS>;
+
+    fn sub(self, other: Fixed<DECIMALS>) -> Fixed<DECIMALS> {
+        Fixed::from_scaled(self.scaled - other.scaled)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adds_zero_point_one_and_zero_point_two_exactly() {
+        let sum = Fixed::<2>::from_f64(0.1) + Fixed::<2>::from_f64(0.2);
+        assert_eq!(sum, Fixed::<2>::from_f64(0.3));
+        assert_ne!(0.1 + 0.2, 0.3);
+    }
+
+    #[test]
+    fn multiplies_two_fixed_point_values() {
+        let product = Fixed::<2>::from_f64(2.5)
+            .checked_mul(Fixed::<2>::from_f64(4.0))
+            .expect("no overflow");
+        assert_eq!(product.to_f64(), 10.0);
+    }
+
+    #[test]
+    fn checked_mul_reports_overflow_instead_of_wrapping() {
+        let huge = Fixed::<2>::from_scaled(i64::MAX);
+        assert_eq!(huge.checked_mul(huge), None);
+    }
+
+    #[test]
+    fn checked_mul_rounds_to_the_nearest_cent_instead_of_truncating() {
+        let product = Fixed::<2>::from_f64(0.33)
+            .checked_mul(Fixed::<2>::from_f64(0.33))
+            .expect("no overflow");
+        assert_eq!(product, Fixed::<2>::from_f64(0.11));
+    }
+
+    #[test]
+    fn checked_mul_rounds_negative_products_away_from_zero() {
+        let product = Fixed::<2>::from_f64(-0.33)
+            .checked_mul(Fixed::<2>::from_f64(0.33))
+            .expect("no overflow");
+        assert_eq!(product, Fixed::<2>::from_f64(-0.11));
+    }
+
+    #[test]
+    fn mul_operator_multiplies_two_fixed_point_values() {
+        let product = Fixed::<2>::from_f64(2.5) * Fixed::<2>::from_f64(4.0);
+        assert_eq!(product.to_f64(), 10.0);
+    }
+}
@@ -0,0 +1,108 @@
+//! Shell-style glob matching for router/config host matching: `*` (any
+//! run of characters), `?` (single character), and `[abc]`/`[a-z]`
+//! character classes. Matched with dynamic programming so patterns with
+//! many `*`s don't backtrack exponentially.
+
+#[derive(Debug, Clone)]
+enum Token {
+    Literal(char),
+    Any,
+    Star,
+    Class(Vec<(char, char)>),
+}
+
+impl Token {
+    fn matches(&self, c: char) -> bool {
+        match self {
+            Token::Literal(expected) => *expected == c,
+            Token::Any => true,
+            Token::Star => true,
+            Token::Class(ranges) => ranges.iter().any(|&(lo, hi)| lo <= c && c <= hi),
+        }
+    }
+}
+
+fn parse_pattern(pattern: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut chars = pattern.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '*' => tokens.push(Token::Star),
+            '?' => tokens.push(Token::Any),
+            '[' => {
+                let mut ranges = Vec::new();
+                while let Some(&next) = chars.peek() {
+                    if next == ']' {
+                        chars.next();
+                        break;
+                    }
+                    chars.next();
+                    if chars.peek() == Some(&'-') {
+                        chars.next();
+                        if let Some(hi) = chars.next() {
+                            ranges.push((next, hi));
+                        }
+                    } else {
+                        ranges.push((next, next));
+                    }
+                }
+                tokens.push(Token::Class(ranges));
+            }
+            _ => tokens.push(Token::Literal(c)),
+        }
+    }
+
+    tokens
+}
+
+/// Matches `text` against a glob `pattern` in O(pattern length * text
+/// length).
+pub fn glob_match(pattern: &str, text: &str) -> bool {
+    let tokens = parse_pattern(pattern);
+    let chars: Vec<char> = text.chars().collect();
+
+    let mut dp = vec![vec![false; chars.len() + 1]; tokens.len() + 1];
+    dp[0][0] = true;
+
+    for i in 0..=tokens.len() {
+        for j in 0..=chars.len() {
+            if i == 0 {
+                continue;
+            }
+            dp[i][j] = match &tokens[i - 1] {
+                Token::Star => dp[i - 1][j] || (j > 0 && dp[i][j - 1]),
+                token => j > 0 && dp[i - 1][j - 1] && token.matches(chars[j - 1]),
+            };
+        }
+    }
+
+    dp[tokens.len()][chars.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn star_and_single_char_wildcards_match() {
+        assert!(glob_match("a*c?", "abcd"));
+    }
+
+    #[test]
+    fn a_character_class_range_rejects_non_matching_input() {
+        assert!(!glob_match("[0-9]*", "x1"));
+        assert!(glob_match("[0-9]*", "1x"));
+    }
+
+    #[test]
+    fn an_explicit_character_set_matches_any_listed_character() {
+        assert!(glob_match("[abc]", "b"));
+        assert!(!glob_match("[abc]", "d"));
+    }
+
+    #[test]
+    fn star_can_match_an_empty_run() {
+        assert!(glob_match("a*b", "ab"));
+    }
+}
@@ -0,0 +1,404 @@
+//! A graph of shared, mutable nodes built on the `Rc<RefCell<_>>` pattern.
+//! Edges are stored as `Weak` references so that cycles don't create
+//! reference cycles that would leak memory.
+
+use std::cell::RefCell;
+use std::cmp::Ordering as CmpOrdering;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::fmt;
+use std::rc::{Rc, Weak};
+
+pub type NodeId = usize;
+
+/// A single node in a [`Graph`]. Nodes are always held behind an
+/// `Rc<RefCell<_>>` so multiple owners can share and mutate them.
+pub struct GraphNode<T> {
+    pub id: NodeId,
+    pub value: T,
+    neighbors: Vec<Weak<RefCell<GraphNode<T>>>>,
+}
+
+pub type NodeHandle<T> = Rc<RefCell<GraphNode<T>>>;
+
+/// A directed graph of `Rc<RefCell<GraphNode<T>>>` nodes.
+#[derive(Default)]
+pub struct Graph<T> {
+    nodes: Vec<NodeHandle<T>>,
+    next_id: NodeId,
+}
+
+impl<T> Graph<T> {
+    pub fn new() -> Self {
+        Graph {
+            nodes: Vec::new(),
+            next_id: 0,
+        }
+    }
+
+    /// Adds a new node holding `value` and returns a shared handle to it.
+    pub fn add_node(&mut self, value: T) -> NodeHandle<T> {
+        let id = self.next_id;
+        self.next_id += 1;
+        let node = Rc::new(RefCell::new(GraphNode {
+            id,
+            value,
+            neighbors: Vec::new(),
+        }));
+        self.nodes.push(Rc::clone(&node));
+        node
+    }
+
+    /// Adds a directed edge `from -> to`.
+    pub fn add_edge(&mut self, from: &NodeHandle<T>, to: &NodeHandle<T>) {
+        from.borrow_mut().neighbors.push(Rc::downgrade(to));
+    }
+
+    /// Breadth-first traversal starting at `start`, returning each visited
+    /// node's id in visit order. Node identity (not value equality) is used
+    /// to guard against revisiting nodes in a cyclic graph.
+    pub fn bfs(&self, start: &NodeHandle<T>) -> Vec<NodeId> {
+        let mut visited = HashSet::new();
+        let mut order = Vec::new();
+        let mut queue = VecDeque::new();
+
+        visited.insert(start.borrow().id);
+        queue.push_back(Rc::clone(start));
+
+        while let Some(current) = queue.pop_front() {
+            order.push(current.borrow().id);
+            for weak in &current.borrow().neighbors {
+                if let Some(neighbor) = weak.upgrade() {
+                    if visited.insert(neighbor.borrow().id) {
+                        queue.push_back(neighbor);
+                    }
+                }
+            }
+        }
+        order
+    }
+
+    /// Depth-first traversal starting at `start`, returning each visited
+    /// node's id in visit order.
+    pub fn dfs(&self, start: &NodeHandle<T>) -> Vec<NodeId> {
+        let mut visited = HashSet::new();
+        let mut order = Vec::new();
+        let mut stack = vec![Rc::clone(start)];
+
+        while let Some(current) = stack.pop() {
+            let id = current.borrow().id;
+            if !visited.insert(id) {
+                continue;
+            }
+            order.push(id);
+            let neighbors: Vec<_> = current
+                .borrow()
+                .neighbors
+                .iter()
+                .filter_map(|w| w.upgrade())
+                .collect();
+            for neighbor in neighbors.into_iter().rev() {
+                if !visited.contains(&neighbor.borrow().id) {
+                    stack.push(neighbor);
+                }
+            }
+        }
+        order
+    }
+
+    /// Returns each node's out-neighbor ids, resolving `Weak` references
+    /// that are still alive.
+    fn adjacency(&self) -> HashMap<NodeId, Vec<NodeId>> {
+        self.nodes
+            .iter()
+            .map(|node| {
+                let node = node.borrow();
+                let neighbors = node
+                    .neighbors
+                    .iter()
+                    .filter_map(|w| w.upgrade())
+                    .map(|n| n.borrow().id)
+                    .collect();
+                (node.id, neighbors)
+            })
+            .collect()
+    }
+
+    /// Reports whether the graph contains a directed cycle.
+    pub fn has_cycle(&self) -> bool {
+        self.topological_order().is_none()
+    }
+
+    /// Computes a topological order over node ids using Kahn's algorithm,
+    /// or `None` if the graph has a cycle.
+    fn topological_order(&self) -> Option<Vec<NodeId>> {
+        let adjacency = self.adjacency();
+        let mut in_degree: HashMap<NodeId, usize> =
+            self.nodes.iter().map(|n| (n.borrow().id, 0)).collect();
+        for neighbors in adjacency.values() {
+            for &to in neighbors {
+                *in_degree.entry(to).or_insert(0) += 1;
+            }
+        }
+
+        // Deterministic order makes the result reproducible for equal-degree nodes.
+        let mut initial: Vec<NodeId> = in_degree
+            .iter()
+            .filter(|(_, &deg)| deg == 0)
+            .map(|(&id, _)| id)
+            .collect();
+        initial.sort_unstable();
+        let mut queue: VecDeque<NodeId> = initial.into();
+
+        let mut order = Vec::with_capacity(self.nodes.len());
+        while let Some(id) = queue.pop_front() {
+            order.push(id);
+            if let Some(neighbors) = adjacency.get(&id) {
+                let mut freed = Vec::new();
+                for &to in neighbors {
+                    let degree = in_degree.get_mut(&to).expect("neighbor must be tracked");
+                    *degree -= 1;
+                    if *degree == 0 {
+                        freed.push(to);
+                    }
+                }
+                freed.sort_unstable();
+                queue.extend(freed);
+            }
+        }
+
+        if order.len() == self.nodes.len() {
+            Some(order)
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: Clone> Graph<T> {
+    /// Returns a topological ordering of node values, or
+    /// [`GraphError::CycleDetected`] if the graph isn't a DAG.
+    pub fn topological_sort(&self) -> Result<Vec<T>, GraphError> {
+        let order = self.topological_order().ok_or(GraphError::CycleDetected)?;
+        let by_id: HashMap<NodeId, T> = self
+            .nodes
+            .iter()
+            .map(|n| {
+                let n = n.borrow();
+                (n.id, n.value.clone())
+            })
+            .collect();
+        Ok(order
+            .into_iter()
+            .map(|id| by_id.get(&id).expect("id came from this graph").clone())
+            .collect())
+    }
+}
+
+/// Errors returned by [`Graph`] algorithms that require the graph to be
+/// acyclic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphError {
+    CycleDetected,
+}
+
+impl fmt::Display for GraphError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GraphError::CycleDetected => write!(f, "graph contains a cycle"),
+        }
+    }
+}
+
+impl std::error::Error for GraphError {}
+
+/// A directed graph with non-negative, `u64` edge weights, stored as a
+/// plain adjacency list keyed by [`NodeId`]. Unlike [`Graph`], nodes here
+/// are addressed by index rather than by shared handle, which is simpler
+/// to work with for the array-heavy shortest-path algorithms below.
+#[derive(Default)]
+pub struct WeightedGraph<T> {
+    values: Vec<T>,
+    adjacency: Vec<Vec<(NodeId, u64)>>,
+}
+
+impl<T> WeightedGraph<T> {
+    pub fn new() -> Self {
+        WeightedGraph {
+            values: Vec::new(),
+            adjacency: Vec::new(),
+        }
+    }
+
+    pub fn add_node(&mut self, value: T) -> NodeId {
+        let id = self.values.len();
+        self.values.push(value);
+        self.adjacency.push(Vec::new());
+        id
+    }
+
+    /// Adds a directed edge `from -> to` with the given non-negative weight.
+    pub fn add_edge(&mut self, from: NodeId, to: NodeId, weight: u64) {
+        self.adjacency[from].push((to, weight));
+    }
+
+    /// Finds the lowest-cost path from `from` to `to` using Dijkstra's
+    /// algorithm. Returns the path (inclusive of both endpoints) and its
+    /// total cost, or `None` if `to` is unreachable from `from`.
+    pub fn shortest_path(&self, from: NodeId, to: NodeId) -> Option<(Vec<NodeId>, u64)> {
+        let mut dist = vec![u64::MAX; self.values.len()];
+        let mut prev: Vec<Option<NodeId>> = vec![None; self.values.len()];
+        let mut heap = BinaryHeap::new();
+
+        dist[from] = 0;
+        heap.push(DijkstraEntry {
+            cost: 0,
+            node: from,
+        });
+
+        while let Some(DijkstraEntry { cost, node }) = heap.pop() {
+            if node == to {
+                break;
+            }
+            if cost > dist[node] {
+                continue; // a shorter path was already found and processed
+            }
+            for &(next, weight) in &self.adjacency[node] {
+                let candidate = cost + weight;
+                if candidate < dist[next] {
+                    dist[next] = candidate;
+                    prev[next] = Some(node);
+                    heap.push(DijkstraEntry {
+                        cost: candidate,
+                        node: next,
+                    });
+                }
+            }
+        }
+
+        if dist[to] == u64::MAX {
+            return None;
+        }
+
+        let mut path = vec![to];
+        let mut current = to;
+        while let Some(p) = prev[current] {
+            path.push(p);
+            current = p;
+        }
+        path.reverse();
+        Some((path, dist[to]))
+    }
+}
+
+/// Min-heap entry for Dijkstra's algorithm: ordered by cost, ascending,
+/// via a reversed `Ord` implementation (`BinaryHeap` is a max-heap).
+#[derive(Eq, PartialEq)]
+struct DijkstraEntry {
+    cost: u64,
+    node: NodeId,
+}
+
+impl Ord for DijkstraEntry {
+    fn cmp(&self, other: &Self) -> CmpOrdering {
+        other.cost.cmp(&self.cost).then_with(|| self.node.cmp(&other.node))
+    }
+}
+
+impl PartialOrd for DijkstraEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<CmpOrdering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bfs_visits_each_node_of_a_cyclic_graph_once() {
+        let mut graph = Graph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_edge(&a, &b);
+        graph.add_edge(&b, &c);
+        graph.add_edge(&c, &a); // closes the cycle
+
+        let order = graph.bfs(&a);
+        assert_eq!(order.len(), 3);
+        assert_eq!(order[0], a.borrow().id);
+    }
+
+    #[test]
+    fn topological_sort_orders_build_dependencies() {
+        let mut graph = Graph::new();
+        let compile = graph.add_node("compile");
+        let link = graph.add_node("link");
+        let test = graph.add_node("test");
+        let package = graph.add_node("package");
+        graph.add_edge(&compile, &link);
+        graph.add_edge(&link, &test);
+        graph.add_edge(&link, &package);
+        graph.add_edge(&test, &package);
+
+        assert!(!graph.has_cycle());
+        let order = graph.topological_sort().expect("dag has a valid order");
+        let pos = |name: &str| order.iter().position(|v| *v == name).unwrap();
+        assert!(pos("compile") < pos("link"));
+        assert!(pos("link") < pos("test"));
+        assert!(pos("test") < pos("package"));
+    }
+
+    #[test]
+    fn topological_sort_reports_cycle() {
+        let mut graph = Graph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        graph.add_edge(&a, &b);
+        graph.add_edge(&b, &a); // back-edge
+
+        assert!(graph.has_cycle());
+        assert_eq!(graph.topological_sort(), Err(GraphError::CycleDetected));
+    }
+
+    #[test]
+    fn dfs_visits_each_node_of_a_cyclic_graph_once() {
+        let mut graph = Graph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_edge(&a, &b);
+        graph.add_edge(&b, &c);
+        graph.add_edge(&c, &a);
+
+        let order = graph.dfs(&a);
+        assert_eq!(order.len(), 3);
+        assert_eq!(order[0], a.borrow().id);
+    }
+
+    #[test]
+    fn shortest_path_matches_hand_computed_answer() {
+        let mut graph = WeightedGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        let d = graph.add_node("d");
+        graph.add_edge(a, b, 1);
+        graph.add_edge(b, c, 2);
+        graph.add_edge(a, c, 5);
+        graph.add_edge(c, d, 1);
+
+        // a -> b -> c -> d costs 4, cheaper than a -> c -> d at 6.
+        let (path, cost) = graph.shortest_path(a, d).expect("d is reachable");
+        assert_eq!(path, vec![a, b, c, d]);
+        assert_eq!(cost, 4);
+    }
+
+    #[test]
+    fn shortest_path_returns_none_when_unreachable() {
+        let mut graph: WeightedGraph<&str> = WeightedGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        assert_eq!(graph.shortest_path(a, b), None);
+    }
+}
@@ -0,0 +1,98 @@
+//! A fixed-size 2D grid with orthogonal/diagonal neighbor iteration, used
+//! by the maze pathfinder built on top of it.
+
+/// Whether [`Grid::neighbors`] considers only orthogonal (`Four`) or also
+/// diagonal (`Eight`) cells adjacent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Connectivity {
+    Four,
+    Eight,
+}
+
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn index(&self, x: usize, y: usize) -> usize {
+        y * self.width + x
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> &T {
+        &self.cells[self.index(x, y)]
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, value: T) {
+        let index = self.index(x, y);
+        self.cells[index] = value;
+    }
+
+    /// Yields the in-bounds neighbor coordinates of `(x, y)`, skipping any
+    /// that would fall off the edge or a corner of the grid.
+    pub fn neighbors(&self, x: usize, y: usize, connectivity: Connectivity) -> impl Iterator<Item = (usize, usize)> {
+        let mut offsets = vec![(-1, 0), (1, 0), (0, -1), (0, 1)];
+        if connectivity == Connectivity::Eight {
+            offsets.extend([(-1, -1), (-1, 1), (1, -1), (1, 1)]);
+        }
+
+        let width = self.width as isize;
+        let height = self.height as isize;
+        let (x, y) = (x as isize, y as isize);
+
+        offsets.into_iter().filter_map(move |(dx, dy)| {
+            let (nx, ny) = (x + dx, y + dy);
+            if nx >= 0 && nx < width && ny >= 0 && ny < height {
+                Some((nx as usize, ny as usize))
+            } else {
+                None
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_corner_cell_has_exactly_two_orthogonal_neighbors() {
+        let grid = Grid::new(3, 3, 0);
+        let neighbors: Vec<_> = grid.neighbors(0, 0, Connectivity::Four).collect();
+        assert_eq!(neighbors.len(), 2);
+    }
+
+    #[test]
+    fn a_center_cell_has_four_orthogonal_neighbors() {
+        let grid = Grid::new(3, 3, 0);
+        let neighbors: Vec<_> = grid.neighbors(1, 1, Connectivity::Four).collect();
+        assert_eq!(neighbors.len(), 4);
+    }
+
+    #[test]
+    fn get_and_set_round_trip_a_value() {
+        let mut grid = Grid::new(2, 2, 'a');
+        grid.set(1, 0, 'z');
+        assert_eq!(*grid.get(1, 0), 'z');
+        assert_eq!(*grid.get(0, 0), 'a');
+    }
+}
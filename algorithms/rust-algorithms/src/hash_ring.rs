@@ -0,0 +1,100 @@
+//! Consistent hashing for distributed-cache examples: keys map to nodes
+//! via a sorted ring of virtual-node hash points, so removing a node
+//! only remaps the keys that were assigned to it.
+
+use std::collections::BTreeMap;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+pub struct HashRing<N> {
+    virtual_nodes: u32,
+    ring: BTreeMap<u64, N>,
+}
+
+impl<N: Clone + Eq + Hash> HashRing<N> {
+    /// `virtual_nodes` controls how many ring points each real node
+    /// claims; more points spread a node's keys more evenly.
+    pub fn new(virtual_nodes: u32) -> Self {
+        HashRing {
+            virtual_nodes: virtual_nodes.max(1),
+            ring: BTreeMap::new(),
+        }
+    }
+
+    pub fn add_node(&mut self, node: N) {
+        for vnode in 0..self.virtual_nodes {
+            self.ring.insert(hash_point(&node, vnode), node.clone());
+        }
+    }
+
+    /// Removes `node` from the ring; its virtual-node points vacate, and
+    /// their keys fall through to the next node clockwise.
+    pub fn remove_node(&mut self, node: &N) {
+        for vnode in 0..self.virtual_nodes {
+            self.ring.remove(&hash_point(node, vnode));
+        }
+    }
+
+    /// The node responsible for `key`: the first ring point at or after
+    /// `key`'s hash, wrapping around to the smallest point if none.
+    pub fn get_node(&self, key: &str) -> Option<&N> {
+        let point = hash_str(key);
+        self.ring
+            .range(point..)
+            .next()
+            .or_else(|| self.ring.iter().next())
+            .map(|(_, node)| node)
+    }
+}
+
+fn hash_point<N: Hash>(node: &N, vnode: u32) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    node.hash(&mut hasher);
+    vnode.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn hash_str(key: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    key.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_key_maps_to_some_node() {
+        let mut ring = HashRing::new(50);
+        ring.add_node("a".to_string());
+        ring.add_node("b".to_string());
+
+        assert!(ring.get_node("hello").is_some());
+    }
+
+    #[test]
+    fn an_empty_ring_maps_nothing() {
+        let ring: HashRing<String> = HashRing::new(50);
+        assert_eq!(ring.get_node("hello"), None);
+    }
+
+    #[test]
+    fn removing_a_node_only_remaps_the_keys_that_were_on_it() {
+        let mut ring = HashRing::new(100);
+        for node in ["a", "b", "c", "d", "e"] {
+            ring.add_node(node.to_string());
+        }
+
+        let keys: Vec<String> = (0..10_000).map(|i| format!("key-{i}")).collect();
+        let before: Vec<String> = keys.iter().map(|k| ring.get_node(k).unwrap().clone()).collect();
+
+        ring.remove_node(&"c".to_string());
+
+        let after: Vec<String> = keys.iter().map(|k| ring.get_node(k).unwrap().clone()).collect();
+
+        let unchanged = before.iter().zip(after.iter()).filter(|(b, a)| b == a).count();
+        let ratio = unchanged as f64 / keys.len() as f64;
+        assert!(ratio > 0.75, "ratio was {ratio}");
+    }
+}
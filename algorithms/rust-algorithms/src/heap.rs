@@ -0,0 +1,159 @@
+//! Min-heap wrappers over `std::collections::BinaryHeap`, which is a
+//! max-heap by default. `WeightedGraph::shortest_path` in [`crate::graph`]
+//! rolled its own version of this; this is the general-purpose form.
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// A min-heap over any `Ord` type, built on `BinaryHeap<Reverse<T>>`.
+#[derive(Default)]
+pub struct MinHeap<T: Ord> {
+    heap: BinaryHeap<Reverse<T>>,
+}
+
+impl<T: Ord> MinHeap<T> {
+    pub fn new() -> Self {
+        MinHeap {
+            heap: BinaryHeap::new(),
+        }
+    }
+
+    pub fn push(&mut self, value: T) {
+        self.heap.push(Reverse(value));
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        self.heap.pop().map(|Reverse(value)| value)
+    }
+
+    pub fn peek(&self) -> Option<&T> {
+        self.heap.peek().map(|Reverse(value)| value)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+}
+
+/// A min-heap over `(key, priority)` pairs supporting `decrease_key`, for
+/// algorithms like Dijkstra that need to lower an already-queued entry's
+/// priority rather than push a duplicate.
+///
+/// `decrease_key` doesn't remove-and-reinsert into the underlying heap
+/// (that would require a linear scan); instead it pushes a fresh entry and
+/// leaves the stale one in place. `pop` skips stale entries by checking
+/// them against the current best-known priority.
+#[derive(Default)]
+pub struct MinHeapKeyed<K: Ord + Hash + Clone, P: Ord + Clone> {
+    heap: BinaryHeap<Reverse<(P, K)>>,
+    best: HashMap<K, P>,
+}
+
+impl<K: Ord + Hash + Clone, P: Ord + Clone> MinHeapKeyed<K, P> {
+    pub fn new() -> Self {
+        MinHeapKeyed {
+            heap: BinaryHeap::new(),
+            best: HashMap::new(),
+        }
+    }
+
+    /// Inserts `key` with `priority`, or lowers its priority if it's
+    /// already present and `priority` is smaller.
+    pub fn push_or_decrease(&mut self, key: K, priority: P) {
+        let should_push = match self.best.get(&key) {
+            Some(existing) => priority < *existing,
+            None => true,
+        };
+        if should_push {
+            self.best.insert(key.clone(), priority.clone());
+            self.heap.push(Reverse((priority, key)));
+        }
+    }
+
+    /// Lowers `key`'s priority; panics if `key` isn't already present or
+    /// if `priority` isn't actually lower, since that would signal a
+    /// misuse of the decrease-key contract.
+    pub fn decrease_key(&mut self, key: K, priority: P) {
+        let existing = self
+            .best
+            .get(&key)
+            .expect("decrease_key requires the key to already be present");
+        assert!(
+            priority < *existing,
+            "decrease_key requires the new priority to be lower than the existing one"
+        );
+        self.best.insert(key.clone(), priority.clone());
+        self.heap.push(Reverse((priority, key)));
+    }
+
+    /// Pops the key with the lowest current priority, skipping any stale
+    /// entries left behind by `decrease_key`.
+    pub fn pop(&mut self) -> Option<(K, P)> {
+        while let Some(Reverse((priority, key))) = self.heap.pop() {
+            if self.best.get(&key) == Some(&priority) {
+                self.best.remove(&key);
+                return Some((key, priority));
+            }
+        }
+        None
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.best.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn min_heap_pops_in_ascending_order() {
+        let mut heap = MinHeap::new();
+        for value in [5, 1, 4, 2, 3] {
+            heap.push(value);
+        }
+
+        let mut popped = Vec::new();
+        while let Some(value) = heap.pop() {
+            popped.push(value);
+        }
+        assert_eq!(popped, vec![1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn min_heap_peek_matches_next_pop() {
+        let mut heap = MinHeap::new();
+        heap.push(3);
+        heap.push(1);
+        assert_eq!(heap.peek(), Some(&1));
+        assert_eq!(heap.pop(), Some(1));
+    }
+
+    #[test]
+    fn keyed_heap_decrease_key_reorders_entries() {
+        let mut heap = MinHeapKeyed::new();
+        heap.push_or_decrease("a", 10);
+        heap.push_or_decrease("b", 5);
+        heap.decrease_key("a", 1);
+
+        assert_eq!(heap.pop(), Some(("a", 1)));
+        assert_eq!(heap.pop(), Some(("b", 5)));
+        assert!(heap.is_empty());
+    }
+
+    #[test]
+    fn keyed_heap_push_or_decrease_ignores_a_higher_priority() {
+        let mut heap = MinHeapKeyed::new();
+        heap.push_or_decrease("a", 5);
+        heap.push_or_decrease("a", 10);
+
+        assert_eq!(heap.pop(), Some(("a", 5)));
+    }
+}
@@ -0,0 +1,58 @@
+//! Interns repeated strings into small integer `Symbol`s so a lexer or
+//! parser can compare and store identifiers cheaply.
+
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Symbol(usize);
+
+#[derive(Default)]
+pub struct StringInterner {
+    strings: Vec<String>,
+    lookup: HashMap<String, Symbol>,
+}
+
+impl StringInterner {
+    pub fn new() -> Self {
+        StringInterner::default()
+    }
+
+    /// Interns `s`, returning the same `Symbol` for repeated inputs.
+    pub fn intern(&mut self, s: &str) -> Symbol {
+        if let Some(&symbol) = self.lookup.get(s) {
+            return symbol;
+        }
+        let symbol = Symbol(self.strings.len());
+        self.strings.push(s.to_string());
+        self.lookup.insert(s.to_string(), symbol);
+        symbol
+    }
+
+    /// Resolves a previously interned `Symbol` back to its string.
+    pub fn resolve(&self, symbol: Symbol) -> &str {
+        &self.strings[symbol.0]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn repeated_strings_share_a_symbol() {
+        let mut interner = StringInterner::new();
+        let a1 = interner.intern("a");
+        let b = interner.intern("b");
+        let a2 = interner.intern("a");
+
+        assert_eq!(a1, a2);
+        assert_ne!(a1, b);
+    }
+
+    #[test]
+    fn resolve_round_trips_the_original_string() {
+        let mut interner = StringInterner::new();
+        let symbol = interner.intern("hello");
+        assert_eq!(interner.resolve(symbol), "hello");
+    }
+}
@@ -0,0 +1,95 @@
+//! A half-open `[start, end)` interval with containment, overlap,
+//! intersection, and union arithmetic.
+//! [`crate::interval_map::IntervalMap`] does not use this type; it keeps
+//! its own `(start, end)` bounds.
+
+use std::cmp::{max, min};
+use std::ops::Sub;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T: Ord + Copy> Interval<T> {
+    pub fn new(start: T, end: T) -> Self {
+        Interval { start, end }
+    }
+
+    pub fn contains(&self, point: T) -> bool {
+        self.start <= point && point < self.end
+    }
+
+    /// True if the two intervals share at least one point.
+    pub fn overlaps(&self, other: &Interval<T>) -> bool {
+        self.start < other.end && other.start < self.end
+    }
+
+    /// True if the intervals touch end-to-end with no gap, i.e. one ends
+    /// exactly where the other starts.
+    fn adjacent(&self, other: &Interval<T>) -> bool {
+        self.end == other.start || other.end == self.start
+    }
+
+    pub fn intersection(&self, other: &Interval<T>) -> Option<Interval<T>> {
+        if self.overlaps(other) {
+            Some(Interval::new(max(self.start, other.start), min(self.end, other.end)))
+        } else {
+            None
+        }
+    }
+
+    /// Merges the two intervals into one, but only when they overlap or
+    /// are adjacent; otherwise a single interval would cover a gap that
+    /// belongs to neither.
+    pub fn union(&self, other: &Interval<T>) -> Option<Interval<T>> {
+        if self.overlaps(other) || self.adjacent(other) {
+            Some(Interval::new(min(self.start, other.start), max(self.end, other.end)))
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: Ord + Copy + Sub<Output = T>> Interval<T> {
+    pub fn length(&self) -> T {
+        self.end - self.start
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overlapping_intervals_report_overlap_and_their_intersection() {
+        let a = Interval::new(1, 5);
+        let b = Interval::new(4, 8);
+
+        assert!(a.overlaps(&b));
+        assert_eq!(a.intersection(&b), Some(Interval::new(4, 5)));
+    }
+
+    #[test]
+    fn disjoint_intervals_have_no_union() {
+        let a = Interval::new(1, 2);
+        let b = Interval::new(5, 8);
+
+        assert!(!a.overlaps(&b));
+        assert_eq!(a.union(&b), None);
+    }
+
+    #[test]
+    fn adjacent_intervals_union_into_one_span() {
+        let a = Interval::new(1, 5);
+        let b = Interval::new(5, 8);
+
+        assert_eq!(a.union(&b), Some(Interval::new(1, 8)));
+    }
+
+    #[test]
+    fn length_is_the_difference_between_end_and_start() {
+        assert_eq!(Interval::new(3, 10).length(), 7);
+    }
+}
@@ -0,0 +1,92 @@
+//! A `BTreeMap`-based map from half-open `[start, end)` intervals to
+//! values, answering point lookups in `O(log n)` instead of scanning every
+//! interval.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OverlapError<K> {
+    pub start: K,
+    pub end: K,
+}
+
+impl<K: fmt::Debug> fmt::Display for OverlapError<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "interval overlaps the existing entry [{:?}, {:?})",
+            self.start, self.end
+        )
+    }
+}
+
+impl<K: fmt::Debug> std::error::Error for OverlapError<K> {}
+
+/// Maps disjoint half-open intervals `[start, end)` to values, keyed
+/// internally by each interval's start.
+#[derive(Default)]
+pub struct IntervalMap<K: Ord + Copy, V> {
+    // Keyed by interval start; value is (end, V).
+    intervals: BTreeMap<K, (K, V)>,
+}
+
+impl<K: Ord + Copy, V> IntervalMap<K, V> {
+    pub fn new() -> Self {
+        IntervalMap {
+            intervals: BTreeMap::new(),
+        }
+    }
+
+    /// Inserts `[start, end)` -> `value`, rejecting it if it overlaps an
+    /// interval already present.
+    pub fn insert(&mut self, start: K, end: K, value: V) -> Result<(), OverlapError<K>> {
+        if let Some((&existing_start, &(existing_end, _))) =
+            self.intervals.range(..end).next_back()
+        {
+            if existing_end > start {
+                return Err(OverlapError {
+                    start: existing_start,
+                    end: existing_end,
+                });
+            }
+        }
+        self.intervals.insert(start, (end, value));
+        Ok(())
+    }
+
+    /// Returns the value whose interval contains `point`, if any.
+    pub fn get(&self, point: K) -> Option<&V> {
+        let (_, (end, value)) = self.intervals.range(..=point).next_back()?;
+        if point < *end {
+            Some(value)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_resolves_points_to_their_containing_interval() {
+        let mut map = IntervalMap::new();
+        map.insert(0, 10, "A").unwrap();
+        map.insert(10, 20, "B").unwrap();
+
+        assert_eq!(map.get(5), Some(&"A"));
+        assert_eq!(map.get(10), Some(&"B"));
+        assert_eq!(map.get(25), None);
+    }
+
+    #[test]
+    fn insert_rejects_an_overlapping_interval() {
+        let mut map = IntervalMap::new();
+        map.insert(0, 10, "A").unwrap();
+        map.insert(10, 20, "B").unwrap();
+
+        assert!(map.insert(5, 15, "C").is_err());
+    }
+}
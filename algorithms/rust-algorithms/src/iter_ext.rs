@@ -0,0 +1,248 @@
+//! An `Iterator` extension trait for grouping and deduplicating
+//! consecutive elements, generalizing patterns used in
+//! [`crate::document`] and [`crate::case_insensitive_map`].
+
+/// Extra combinators over any `Iterator`, blanket-implemented below.
+pub trait IterExt: Iterator {
+    /// Groups consecutive elements into runs while `pred(previous, next)`
+    /// holds between neighbours; a `false` starts a new chunk.
+    fn chunk_while<F>(self, pred: F) -> ChunkWhile<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item, &Self::Item) -> bool,
+    {
+        ChunkWhile {
+            iter: self,
+            pred,
+            pending: None,
+        }
+    }
+
+    /// Collapses runs of consecutive equal items into a single occurrence.
+    fn dedup_consecutive(self) -> DedupConsecutive<Self>
+    where
+        Self: Sized,
+        Self::Item: PartialEq,
+    {
+        DedupConsecutive {
+            iter: self,
+            previous: None,
+        }
+    }
+
+    /// Yields the accumulated state after each element, like `scan` but
+    /// without needing to wrap every output in `Some`.
+    fn running<S, F>(self, init: S, f: F) -> Running<Self, S, F>
+    where
+        Self: Sized,
+        F: FnMut(&S, Self::Item) -> S,
+    {
+        Running {
+            iter: self,
+            state: init,
+            f,
+        }
+    }
+
+    /// Maps each element through a fallible closure, short-circuiting on
+    /// the first error and reporting the index it occurred at.
+    fn try_map<T, E, F>(self, mut f: F) -> Result<Vec<T>, (usize, E)>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<T, E>,
+    {
+        let mut mapped = Vec::new();
+        for (index, item) in self.enumerate() {
+            match f(item) {
+                Ok(value) => mapped.push(value),
+                Err(err) => return Err((index, err)),
+            }
+        }
+        Ok(mapped)
+    }
+
+    /// Like `try_map`, but doesn't short-circuit: `Ok` values are yielded
+    /// as they come, and `Err` values are set aside in a side channel
+    /// retrievable via `errors()` once the caller is done iterating.
+    fn filter_map_ok<T, E, F>(self, f: F) -> FilterMapOk<Self, F, E>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Result<T, E>,
+    {
+        FilterMapOk {
+            iter: self,
+            f,
+            errors: Vec::new(),
+        }
+    }
+}
+
+impl<I: Iterator> IterExt for I {}
+
+pub struct ChunkWhile<I: Iterator, F> {
+    iter: I,
+    pred: F,
+    pending: Option<I::Item>,
+}
+
+impl<I, F> Iterator for ChunkWhile<I, F>
+where
+    I: Iterator,
+    F: FnMut(&I::Item, &I::Item) -> bool,
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = self.pending.take().or_else(|| self.iter.next())?;
+        let mut chunk = vec![first];
+        for next in self.iter.by_ref() {
+            if (self.pred)(chunk.last().unwrap(), &next) {
+                chunk.push(next);
+            } else {
+                self.pending = Some(next);
+                break;
+            }
+        }
+        Some(chunk)
+    }
+}
+
+pub struct DedupConsecutive<I: Iterator> {
+    iter: I,
+    previous: Option<I::Item>,
+}
+
+impl<I> Iterator for DedupConsecutive<I>
+where
+    I: Iterator,
+    I::Item: PartialEq + Clone,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for item in self.iter.by_ref() {
+            if self.previous.as_ref() != Some(&item) {
+                self.previous = Some(item.clone());
+                return Some(item);
+            }
+        }
+        None
+    }
+}
+
+pub struct Running<I, S, F> {
+    iter: I,
+    state: S,
+    f: F,
+}
+
+impl<I, S, F> Iterator for Running<I, S, F>
+where
+    I: Iterator,
+    S: Clone,
+    F: FnMut(&S, I::Item) -> S,
+{
+    type Item = S;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        self.state = (self.f)(&self.state, item);
+        Some(self.state.clone())
+    }
+}
+
+pub struct FilterMapOk<I, F, E> {
+    iter: I,
+    f: F,
+    errors: Vec<E>,
+}
+
+impl<I, F, E> FilterMapOk<I, F, E> {
+    /// The errors seen so far, in the order they were produced. Only
+    /// reflects elements the underlying iterator has already yielded.
+    pub fn errors(&self) -> &[E] {
+        &self.errors
+    }
+}
+
+impl<I, T, E, F> Iterator for FilterMapOk<I, F, E>
+where
+    I: Iterator,
+    F: FnMut(I::Item) -> Result<T, E>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for item in self.iter.by_ref() {
+            match (self.f)(item) {
+                Ok(value) => return Some(value),
+                Err(err) => self.errors.push(err),
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chunk_while_groups_runs_of_consecutive_integers() {
+        let chunks: Vec<Vec<i32>> = [1, 2, 5, 6, 7, 10]
+            .into_iter()
+            .chunk_while(|a, b| b - a == 1)
+            .collect();
+
+        assert_eq!(chunks, vec![vec![1, 2], vec![5, 6, 7], vec![10]]);
+    }
+
+    #[test]
+    fn chunk_while_handles_an_empty_iterator() {
+        let chunks: Vec<Vec<i32>> = Vec::<i32>::new().into_iter().chunk_while(|_, _| true).collect();
+        assert!(chunks.is_empty());
+    }
+
+    #[test]
+    fn dedup_consecutive_collapses_runs_of_equal_items() {
+        let deduped: Vec<i32> = [1, 1, 2, 2, 2, 1, 3, 3].into_iter().dedup_consecutive().collect();
+        assert_eq!(deduped, vec![1, 2, 1, 3]);
+    }
+
+    #[test]
+    fn running_yields_the_running_sum() {
+        let sums: Vec<i32> = [1, 2, 3].into_iter().running(0, |acc, x| acc + x).collect();
+        assert_eq!(sums, vec![1, 3, 6]);
+    }
+
+    #[test]
+    fn running_yields_the_running_max() {
+        let maxes: Vec<i32> = [3, 1, 4, 1, 5].into_iter().running(i32::MIN, |acc, x| (*acc).max(x)).collect();
+        assert_eq!(maxes, vec![3, 3, 4, 4, 5]);
+    }
+
+    #[test]
+    fn try_map_collects_all_values_when_every_element_succeeds() {
+        let parsed = ["1", "2", "3"].into_iter().try_map(|s| s.parse::<i32>());
+        assert_eq!(parsed, Ok(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn try_map_reports_the_index_of_the_first_failure() {
+        let result = ["1", "oops", "3"].into_iter().try_map(|s| s.parse::<i32>());
+        let (index, _err) = result.unwrap_err();
+        assert_eq!(index, 1);
+    }
+
+    #[test]
+    fn filter_map_ok_yields_successes_and_collects_failures_on_the_side() {
+        let mut parsed = ["1", "oops", "3", "nope", "5"]
+            .into_iter()
+            .filter_map_ok(|s| s.parse::<i32>());
+
+        let values: Vec<i32> = parsed.by_ref().collect();
+
+        assert_eq!(values, vec![1, 3, 5]);
+        assert_eq!(parsed.errors().len(), 2);
+    }
+}
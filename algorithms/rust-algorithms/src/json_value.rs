@@ -0,0 +1,436 @@
+//! A small JSON value type, so structured data can be printed without a
+//! heavy dependency.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fmt::Write as _;
+use std::iter::Peekable;
+use std::str::CharIndices;
+
+/// An error produced while parsing JSON text, with the byte position
+/// where it occurred.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonError {
+    pub message: String,
+    pub position: usize,
+}
+
+impl fmt::Display for JsonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (at byte {})", self.message, self.position)
+    }
+}
+
+impl std::error::Error for JsonError {}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    Number(f64),
+    Str(String),
+    Array(Vec<Value>),
+    Object(BTreeMap<String, Value>),
+}
+
+impl fmt::Display for Value {
+    /// Compact JSON, with no extra whitespace.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut out = String::new();
+        self.write_compact(&mut out);
+        f.write_str(&out)
+    }
+}
+
+impl Value {
+    /// Parses `input` as a single JSON value, reporting the byte
+    /// position of the first parse error.
+    pub fn parse(input: &str) -> Result<Value, JsonError> {
+        let mut parser = Parser {
+            input,
+            chars: input.char_indices().peekable(),
+        };
+        parser.skip_whitespace();
+        let value = parser.parse_value()?;
+        parser.skip_whitespace();
+        if let Some(&(position, _)) = parser.chars.peek() {
+            return Err(JsonError {
+                message: "trailing input after JSON value".to_string(),
+                position,
+            });
+        }
+        Ok(value)
+    }
+
+    /// Looks up a dotted path with optional `[index]` array access, e.g.
+    /// `"user.roles[0]"`, returning `None` at the first missing key or
+    /// out-of-range index.
+    pub fn get_path(&self, path: &str) -> Option<&Value> {
+        let mut current = self;
+        for segment in path.split('.') {
+            let (key, indices) = split_indices(segment);
+            if !key.is_empty() {
+                current = current.as_object()?.get(key)?;
+            }
+            for index in indices {
+                current = current.as_array()?.get(index)?;
+            }
+        }
+        Some(current)
+    }
+
+    fn as_object(&self) -> Option<&BTreeMap<String, Value>> {
+        match self {
+            Value::Object(entries) => Some(entries),
+            _ => None,
+        }
+    }
+
+    fn as_array(&self) -> Option<&Vec<Value>> {
+        match self {
+            Value::Array(items) => Some(items),
+            _ => None,
+        }
+    }
+
+    /// JSON indented by `indent` spaces per nesting level.
+    pub fn to_pretty(&self, indent: usize) -> String {
+        let mut out = String::new();
+        self.write_pretty(&mut out, indent, 0);
+        out
+    }
+
+    fn write_compact(&self, out: &mut String) {
+        match self {
+            Value::Null => out.push_str("null"),
+            Value::Bool(b) => out.push_str(if *b { "true" } else { "false" }),
+            Value::Number(n) => {
+                let _ = write!(out, "{n}");
+            }
+            Value::Str(s) => write_json_string(out, s),
+            Value::Array(items) => {
+                out.push('[');
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    item.write_compact(out);
+                }
+                out.push(']');
+            }
+            Value::Object(entries) => {
+                out.push('{');
+                for (i, (key, value)) in entries.iter().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    write_json_string(out, key);
+                    out.push(':');
+                    value.write_compact(out);
+                }
+                out.push('}');
+            }
+        }
+    }
+
+    fn write_pretty(&self, out: &mut String, indent: usize, depth: usize) {
+        match self {
+            Value::Array(items) if !items.is_empty() => {
+                out.push_str("[\n");
+                for (i, item) in items.iter().enumerate() {
+                    push_indent(out, indent, depth + 1);
+                    item.write_pretty(out, indent, depth + 1);
+                    if i + 1 < items.len() {
+                        out.push(',');
+                    }
+                    out.push('\n');
+                }
+                push_indent(out, indent, depth);
+                out.push(']');
+            }
+            Value::Object(entries) if !entries.is_empty() => {
+                out.push_str("{\n");
+                for (i, (key, value)) in entries.iter().enumerate() {
+                    push_indent(out, indent, depth + 1);
+                    write_json_string(out, key);
+                    out.push_str(": ");
+                    value.write_pretty(out, indent, depth + 1);
+                    if i + 1 < entries.len() {
+                        out.push(',');
+                    }
+                    out.push('\n');
+                }
+                push_indent(out, indent, depth);
+                out.push('}');
+            }
+            _ => self.write_compact(out),
+        }
+    }
+}
+
+fn push_indent(out: &mut String, indent: usize, depth: usize) {
+    for _ in 0..indent * depth {
+        out.push(' ');
+    }
+}
+
+fn write_json_string(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            _ => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+/// Splits a path segment like `"roles[0][1]"` into its key (`"roles"`)
+/// and the array indices that follow it, in order.
+fn split_indices(segment: &str) -> (&str, Vec<usize>) {
+    let key_end = segment.find('[').unwrap_or(segment.len());
+    let (key, mut rest) = segment.split_at(key_end);
+
+    let mut indices = Vec::new();
+    while let Some(open) = rest.strip_prefix('[') {
+        let Some(close) = open.find(']') else { break };
+        if let Ok(index) = open[..close].parse() {
+            indices.push(index);
+        }
+        rest = &open[close + 1..];
+    }
+
+    (key, indices)
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    chars: Peekable<CharIndices<'a>>,
+}
+
+impl<'a> Parser<'a> {
+    fn skip_whitespace(&mut self) {
+        while self.chars.next_if(|&(_, c)| c.is_ascii_whitespace()).is_some() {}
+    }
+
+    fn error_here(&mut self, message: &str) -> JsonError {
+        let position = self.chars.peek().map_or(self.input.len(), |&(i, _)| i);
+        JsonError {
+            message: message.to_string(),
+            position,
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), JsonError> {
+        match self.chars.next_if(|&(_, c)| c == expected) {
+            Some(_) => Ok(()),
+            None => Err(self.error_here(&format!("expected '{expected}'"))),
+        }
+    }
+
+    fn parse_value(&mut self) -> Result<Value, JsonError> {
+        self.skip_whitespace();
+        match self.chars.peek() {
+            Some(&(_, '{')) => self.parse_object(),
+            Some(&(_, '[')) => self.parse_array(),
+            Some(&(_, '"')) => self.parse_string().map(Value::Str),
+            Some(&(_, c)) if c == '-' || c.is_ascii_digit() => self.parse_number(),
+            Some(&(pos, 't')) => self.parse_literal("true", pos, Value::Bool(true)),
+            Some(&(pos, 'f')) => self.parse_literal("false", pos, Value::Bool(false)),
+            Some(&(pos, 'n')) => self.parse_literal("null", pos, Value::Null),
+            _ => Err(self.error_here("expected a JSON value")),
+        }
+    }
+
+    fn parse_literal(&mut self, literal: &str, position: usize, value: Value) -> Result<Value, JsonError> {
+        if self.input[position..].starts_with(literal) {
+            for _ in 0..literal.len() {
+                self.chars.next();
+            }
+            Ok(value)
+        } else {
+            Err(self.error_here(&format!("expected `{literal}`")))
+        }
+    }
+
+    fn parse_object(&mut self) -> Result<Value, JsonError> {
+        self.expect('{')?;
+        let mut entries = BTreeMap::new();
+        self.skip_whitespace();
+        if self.chars.next_if(|&(_, c)| c == '}').is_some() {
+            return Ok(Value::Object(entries));
+        }
+
+        loop {
+            self.skip_whitespace();
+            let key = self.parse_string()?;
+            self.skip_whitespace();
+            self.expect(':')?;
+            let value = self.parse_value()?;
+            entries.insert(key, value);
+
+            self.skip_whitespace();
+            match self.chars.peek() {
+                Some(&(_, ',')) => {
+                    self.chars.next();
+                }
+                Some(&(_, '}')) => {
+                    self.chars.next();
+                    break;
+                }
+                _ => return Err(self.error_here("expected ',' or '}'")),
+            }
+        }
+
+        Ok(Value::Object(entries))
+    }
+
+    fn parse_array(&mut self) -> Result<Value, JsonError> {
+        self.expect('[')?;
+        let mut items = Vec::new();
+        self.skip_whitespace();
+        if self.chars.next_if(|&(_, c)| c == ']').is_some() {
+            return Ok(Value::Array(items));
+        }
+
+        loop {
+            items.push(self.parse_value()?);
+            self.skip_whitespace();
+            match self.chars.peek() {
+                Some(&(_, ',')) => {
+                    self.chars.next();
+                }
+                Some(&(_, ']')) => {
+                    self.chars.next();
+                    break;
+                }
+                _ => return Err(self.error_here("expected ',' or ']'")),
+            }
+        }
+
+        Ok(Value::Array(items))
+    }
+
+    fn parse_string(&mut self) -> Result<String, JsonError> {
+        self.expect('"')?;
+        let mut result = String::new();
+        loop {
+            match self.chars.next() {
+                Some((_, '"')) => return Ok(result),
+                Some((_, '\\')) => match self.chars.next() {
+                    Some((_, '"')) => result.push('"'),
+                    Some((_, '\\')) => result.push('\\'),
+                    Some((_, '/')) => result.push('/'),
+                    Some((_, 'n')) => result.push('\n'),
+                    Some((_, 't')) => result.push('\t'),
+                    Some((_, 'r')) => result.push('\r'),
+                    _ => return Err(self.error_here("invalid escape sequence")),
+                },
+                Some((_, c)) => result.push(c),
+                None => return Err(self.error_here("unterminated string")),
+            }
+        }
+    }
+
+    fn parse_number(&mut self) -> Result<Value, JsonError> {
+        let start = self.chars.peek().unwrap().0;
+        self.chars.next_if(|&(_, c)| c == '-');
+        while self.chars.next_if(|&(_, c)| c.is_ascii_digit()).is_some() {}
+        if self.chars.next_if(|&(_, c)| c == '.').is_some() {
+            while self.chars.next_if(|&(_, c)| c.is_ascii_digit()).is_some() {}
+        }
+        if self.chars.next_if(|&(_, c)| c == 'e' || c == 'E').is_some() {
+            self.chars.next_if(|&(_, c)| c == '+' || c == '-');
+            while self.chars.next_if(|&(_, c)| c.is_ascii_digit()).is_some() {}
+        }
+
+        let end = self.chars.peek().map_or(self.input.len(), |&(i, _)| i);
+        self.input[start..end]
+            .parse()
+            .map(Value::Number)
+            .map_err(|_| JsonError {
+                message: "invalid number".to_string(),
+                position: start,
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Value {
+        let mut object = BTreeMap::new();
+        object.insert("name".to_string(), Value::Str("ada".to_string()));
+        object.insert("active".to_string(), Value::Bool(true));
+        object.insert(
+            "scores".to_string(),
+            Value::Array(vec![Value::Number(1.0), Value::Number(2.5), Value::Null]),
+        );
+        Value::Object(object)
+    }
+
+    #[test]
+    fn compact_output_parses_as_valid_json() {
+        let json = sample().to_string();
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed["name"], "ada");
+        assert_eq!(parsed["scores"][1], 2.5);
+    }
+
+    #[test]
+    fn pretty_output_is_indented_per_nesting_level() {
+        let pretty = Value::Object(BTreeMap::from([(
+            "a".to_string(),
+            Value::Array(vec![Value::Number(1.0)]),
+        )]))
+        .to_pretty(2);
+
+        assert_eq!(pretty, "{\n  \"a\": [\n    1\n  ]\n}");
+    }
+
+    #[test]
+    fn an_empty_array_or_object_stays_on_one_line() {
+        assert_eq!(Value::Array(vec![]).to_pretty(2), "[]");
+        assert_eq!(Value::Object(BTreeMap::new()).to_pretty(2), "{}");
+    }
+
+    #[test]
+    fn parsing_then_printing_round_trips_semantically() {
+        let parsed = Value::parse(r#"{"a":[1,2,null],"b":true}"#).unwrap();
+
+        let mut expected = BTreeMap::new();
+        expected.insert(
+            "a".to_string(),
+            Value::Array(vec![Value::Number(1.0), Value::Number(2.0), Value::Null]),
+        );
+        expected.insert("b".to_string(), Value::Bool(true));
+
+        assert_eq!(parsed, Value::Object(expected));
+        assert_eq!(Value::parse(&parsed.to_string()).unwrap(), parsed);
+    }
+
+    #[test]
+    fn a_malformed_input_reports_a_position() {
+        let err = Value::parse("{\"a\": }").unwrap_err();
+        assert_eq!(err.position, 6);
+    }
+
+    #[test]
+    fn get_path_navigates_dotted_keys_and_array_indices() {
+        let value = Value::parse(r#"{"user":{"roles":["admin","editor"]}}"#).unwrap();
+
+        assert_eq!(value.get_path("user.roles[0]"), Some(&Value::Str("admin".to_string())));
+    }
+
+    #[test]
+    fn get_path_returns_none_for_a_missing_segment_or_bad_index() {
+        let value = Value::parse(r#"{"user":{"roles":["admin"]}}"#).unwrap();
+
+        assert_eq!(value.get_path("user.missing"), None);
+        assert_eq!(value.get_path("user.roles[5]"), None);
+    }
+}
@@ -0,0 +1,98 @@
+//! Lazily merges any number of already-sorted iterators into one sorted
+//! stream, pulling only as many elements as the caller consumes.
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+struct HeadOfStream<T, I> {
+    head: T,
+    rest: I,
+}
+
+impl<T: PartialEq, I> PartialEq for HeadOfStream<T, I> {
+    fn eq(&self, other: &Self) -> bool {
+        self.head == other.head
+    }
+}
+
+impl<T: Eq, I> Eq for HeadOfStream<T, I> {}
+
+impl<T: PartialOrd, I> PartialOrd for HeadOfStream<T, I> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        self.head.partial_cmp(&other.head)
+    }
+}
+
+impl<T: Ord, I> Ord for HeadOfStream<T, I> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.head.cmp(&other.head)
+    }
+}
+
+/// Merges `iters`, each assumed already sorted ascending, into a single
+/// ascending iterator, without deduplicating equal values.
+pub fn kway_merge<T: Ord, I: Iterator<Item = T>>(iters: Vec<I>) -> impl Iterator<Item = T> {
+    let mut heap = BinaryHeap::new();
+    for mut iter in iters {
+        if let Some(head) = iter.next() {
+            heap.push(Reverse(HeadOfStream { head, rest: iter }));
+        }
+    }
+
+    std::iter::from_fn(move || {
+        let Reverse(HeadOfStream { head, mut rest }) = heap.pop()?;
+        if let Some(next_head) = rest.next() {
+            heap.push(Reverse(HeadOfStream { head: next_head, rest }));
+        }
+        Some(head)
+    })
+}
+
+/// Like [`kway_merge`], but skips a value equal to the one just yielded.
+pub fn kway_merge_dedup<T: Ord + Clone, I: Iterator<Item = T>>(iters: Vec<I>) -> impl Iterator<Item = T> {
+    let mut last: Option<T> = None;
+    kway_merge(iters).filter(move |value| {
+        let is_duplicate = last.as_ref() == Some(value);
+        if !is_duplicate {
+            last = Some(value.clone());
+        }
+        !is_duplicate
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merges_three_sorted_vecs_into_one_sorted_stream() {
+        let a = vec![1, 4, 7];
+        let b = vec![2, 5, 8];
+        let c = vec![3, 6, 9];
+
+        let merged: Vec<i32> = kway_merge(vec![a.into_iter(), b.into_iter(), c.into_iter()]).collect();
+
+        assert_eq!(merged, vec![1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    }
+
+    #[test]
+    fn dedup_merge_drops_repeated_values() {
+        let a = vec![1, 2, 4];
+        let b = vec![2, 3, 4];
+
+        let merged: Vec<i32> = kway_merge_dedup(vec![a.into_iter(), b.into_iter()]).collect();
+
+        assert_eq!(merged, vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn stays_lazy_over_iterators_that_would_be_too_large_to_collect() {
+        let a = 0..1_000_000_000u64;
+        let b = 1_000_000_000..2_000_000_000u64;
+
+        let mut merged = kway_merge(vec![a, b]);
+
+        assert_eq!(merged.next(), Some(0));
+        assert_eq!(merged.next(), Some(1));
+    }
+}
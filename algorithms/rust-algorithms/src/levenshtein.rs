@@ -0,0 +1,66 @@
+//! Edit distance for autocomplete/spell-check use cases, iterating over
+//! `char`s (not bytes) so multi-byte text isn't split mid-character.
+
+/// The standard dynamic-programming edit distance between `a` and `b`.
+pub fn levenshtein(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for i in 1..=a.len() {
+        let mut previous_diagonal = row[0];
+        row[0] = i;
+        for j in 1..=b.len() {
+            let temp = row[j];
+            row[j] = if a[i - 1] == b[j - 1] {
+                previous_diagonal
+            } else {
+                1 + previous_diagonal.min(row[j]).min(row[j - 1])
+            };
+            previous_diagonal = temp;
+        }
+    }
+
+    row[b.len()]
+}
+
+/// Every candidate within `max_distance` of `query`, sorted by ascending
+/// edit distance.
+pub fn closest_matches<'a>(query: &str, candidates: &[&'a str], max_distance: usize) -> Vec<(&'a str, usize)> {
+    let mut matches: Vec<(&str, usize)> = candidates
+        .iter()
+        .map(|&candidate| (candidate, levenshtein(query, candidate)))
+        .filter(|&(_, distance)| distance <= max_distance)
+        .collect();
+
+    matches.sort_by_key(|&(_, distance)| distance);
+    matches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_the_classic_kitten_sitting_example() {
+        assert_eq!(levenshtein("kitten", "sitting"), 3);
+    }
+
+    #[test]
+    fn identical_strings_have_zero_distance() {
+        assert_eq!(levenshtein("same", "same"), 0);
+    }
+
+    #[test]
+    fn multi_byte_characters_count_as_one_edit() {
+        assert_eq!(levenshtein("café", "cafe"), 1);
+    }
+
+    #[test]
+    fn closest_matches_ranks_candidates_for_a_typo() {
+        let candidates = ["apple", "appla", "banana", "app"];
+        let matches = closest_matches("apple", &candidates, 2);
+
+        assert_eq!(matches, vec![("apple", 0), ("appla", 1), ("app", 2)]);
+    }
+}
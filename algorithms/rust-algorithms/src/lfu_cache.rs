@@ -0,0 +1,148 @@
+//! A least-frequently-used cache, complementing an LRU with eviction by
+//! access count instead of recency (ties broken by least-recently-used),
+//! using frequency buckets so eviction is O(1). Moving a key to the next
+//! bucket on access scans its old bucket, so that step is linear in the
+//! bucket's size.
+
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+
+struct Entry<V> {
+    value: V,
+    frequency: u64,
+}
+
+pub struct LfuCache<K, V> {
+    capacity: usize,
+    entries: HashMap<K, Entry<V>>,
+    buckets: HashMap<u64, VecDeque<K>>,
+    min_frequency: u64,
+}
+
+impl<K: Eq + Hash + Clone, V> LfuCache<K, V> {
+    pub fn new(capacity: usize) -> Self {
+        LfuCache {
+            capacity,
+            entries: HashMap::new(),
+            buckets: HashMap::new(),
+            min_frequency: 0,
+        }
+    }
+
+    pub fn get(&mut self, key: &K) -> Option<&V> {
+        if !self.entries.contains_key(key) {
+            return None;
+        }
+        self.bump_frequency(key);
+        self.entries.get(key).map(|entry| &entry.value)
+    }
+
+    pub fn put(&mut self, key: K, value: V) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        if self.entries.contains_key(&key) {
+            self.entries.get_mut(&key).unwrap().value = value;
+            self.bump_frequency(&key);
+            return;
+        }
+
+        if self.entries.len() >= self.capacity {
+            self.evict();
+        }
+
+        self.entries.insert(key.clone(), Entry { value, frequency: 1 });
+        self.buckets.entry(1).or_default().push_back(key);
+        self.min_frequency = 1;
+    }
+
+    fn bump_frequency(&mut self, key: &K) {
+        let frequency = self.entries.get(key).unwrap().frequency;
+
+        let bucket = self.buckets.get_mut(&frequency).unwrap();
+        bucket.retain(|k| k != key);
+        if bucket.is_empty() {
+            self.buckets.remove(&frequency);
+            if self.min_frequency == frequency {
+                self.min_frequency += 1;
+            }
+        }
+
+        let new_frequency = frequency + 1;
+        self.entries.get_mut(key).unwrap().frequency = new_frequency;
+        self.buckets.entry(new_frequency).or_default().push_back(key.clone());
+    }
+
+    fn evict(&mut self) {
+        let Some(bucket) = self.buckets.get_mut(&self.min_frequency) else {
+            return;
+        };
+        if let Some(evicted) = bucket.pop_front() {
+            self.entries.remove(&evicted);
+        }
+        if bucket.is_empty() {
+            self.buckets.remove(&self.min_frequency);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn evicts_the_least_frequently_used_entry() {
+        let mut cache = LfuCache::new(2);
+        cache.put("hot", 1);
+        cache.put("cold", 2);
+
+        for _ in 0..5 {
+            cache.get(&"hot");
+        }
+        cache.get(&"cold");
+
+        cache.put("new", 3);
+
+        assert_eq!(cache.get(&"hot"), Some(&1));
+        assert_eq!(cache.get(&"cold"), None);
+        assert_eq!(cache.get(&"new"), Some(&3));
+    }
+
+    #[test]
+    fn ties_in_frequency_are_broken_by_least_recently_used() {
+        let mut cache = LfuCache::new(2);
+        cache.put("a", 1);
+        cache.put("b", 2);
+        cache.get(&"a");
+        cache.get(&"b");
+
+        cache.put("c", 3);
+
+        assert_eq!(cache.get(&"a"), None);
+        assert_eq!(cache.get(&"b"), Some(&2));
+        assert_eq!(cache.get(&"c"), Some(&3));
+    }
+
+    #[test]
+    fn a_zero_capacity_cache_never_stores_anything() {
+        let mut cache = LfuCache::new(0);
+        cache.put("a", 1);
+        assert_eq!(cache.get(&"a"), None);
+    }
+
+    #[test]
+    fn evicting_a_high_frequency_entry_does_not_leave_a_stale_empty_bucket() {
+        let mut cache = LfuCache::new(1);
+        cache.put("a", 1);
+        cache.get(&"a");
+        cache.get(&"a");
+        cache.get(&"a");
+        // "a" is now the cache's only entry, at frequency 4, so
+        // `min_frequency` points at bucket 4 when eviction hits it below.
+
+        cache.put("b", 2);
+
+        assert!(!cache.buckets.contains_key(&4));
+    }
+}
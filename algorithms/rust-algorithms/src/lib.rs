@@ -0,0 +1,89 @@
+//! A grab-bag of algorithms and data structures written while working
+//! through the notes in this repo.
+
+pub mod arena;
+pub mod args;
+pub mod batching_observer;
+pub mod bin_format;
+pub mod bitset;
+pub mod bloom_filter;
+pub mod builder_macro;
+pub mod case_insensitive_map;
+pub mod command_history;
+pub mod config;
+pub mod config_cascade;
+pub mod convert;
+pub mod cow_tree;
+pub mod debounce;
+pub mod defer;
+pub mod deps;
+pub mod deterministic_map;
+pub mod diff;
+pub mod dim;
+pub mod document;
+pub mod ema;
+pub mod event_bus;
+pub mod expr;
+pub mod fenwick_tree;
+pub mod fixed;
+pub mod glob_match;
+pub mod graph;
+pub mod grid;
+pub mod hash_ring;
+pub mod heap;
+pub mod interner;
+pub mod interval;
+pub mod interval_map;
+pub mod iter_ext;
+pub mod json_value;
+pub mod kway_merge;
+pub mod levenshtein;
+pub mod lfu_cache;
+pub mod matrix;
+pub mod maze;
+pub mod message;
+pub mod moving_median;
+pub mod numerics;
+pub mod object_pool;
+pub mod observer;
+pub mod par_map;
+pub mod parse_from;
+pub mod persistent_vec;
+pub mod piece_table;
+pub mod pipeline;
+pub mod prefix_sum;
+pub mod pretty;
+pub mod quantiles;
+pub mod rate_limiter;
+pub mod regex;
+pub mod request_builder;
+pub mod reservoir_sampler;
+pub mod resolver;
+pub mod result_ext;
+pub mod retry;
+pub mod ring_buffer;
+pub mod rng;
+pub mod rope;
+pub mod router;
+pub mod sealed;
+pub mod search;
+pub mod shapes;
+pub mod sliding_counter;
+pub mod sliding_window_max;
+pub mod sorting;
+pub mod state_machine;
+pub mod stream_lexer;
+pub mod strings;
+pub mod template;
+pub mod timeout;
+pub mod top_k;
+pub mod transactional;
+pub mod tree;
+pub mod tree_format;
+pub mod trie;
+pub mod type_map;
+pub mod units;
+pub mod valid_index;
+pub mod version;
+pub mod weak_cache;
+pub mod weighted_sampler;
@@ -0,0 +1,74 @@
+//! A const-generic matrix, extending the fixed-size array notes to two
+//! dimensions: `transpose` and `multiply` carry their dimensions in the
+//! type, so a shape mismatch is a compile error rather than a panic.
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Matrix<const R: usize, const C: usize> {
+    rows: [[f64; C]; R],
+}
+
+impl<const R: usize, const C: usize> Matrix<R, C> {
+    pub fn new(rows: [[f64; C]; R]) -> Self {
+        Matrix { rows }
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> f64 {
+        self.rows[row][col]
+    }
+
+    /// Flips rows and columns; a `Matrix<R, C>` becomes a `Matrix<C, R>`.
+    pub fn transpose(&self) -> Matrix<C, R> {
+        let mut result = [[0.0; R]; C];
+        for (r, row) in self.rows.iter().enumerate() {
+            for (c, &value) in row.iter().enumerate() {
+                result[c][r] = value;
+            }
+        }
+        Matrix::new(result)
+    }
+
+    /// Multiplies this `R x C` matrix by a `C x K` matrix, producing an
+    /// `R x K` matrix. The shared `C` dimension is enforced at compile
+    /// time by the shared const parameter.
+    pub fn multiply<const K: usize>(&self, other: &Matrix<C, K>) -> Matrix<R, K> {
+        let mut result = [[0.0; K]; R];
+        for (row, out_row) in self.rows.iter().zip(result.iter_mut()) {
+            for (k, out_cell) in out_row.iter_mut().enumerate() {
+                *out_cell = row
+                    .iter()
+                    .zip(other.rows.iter())
+                    .map(|(&value, other_row)| value * other_row[k])
+                    .sum();
+            }
+        }
+        Matrix::new(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn multiplies_a_2x3_by_a_3x2() {
+        let a = Matrix::new([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let b = Matrix::new([[7.0, 8.0], [9.0, 10.0], [11.0, 12.0]]);
+
+        let product = a.multiply(&b);
+
+        assert_eq!(product.get(0, 0), 58.0);
+        assert_eq!(product.get(0, 1), 64.0);
+        assert_eq!(product.get(1, 0), 139.0);
+        assert_eq!(product.get(1, 1), 154.0);
+    }
+
+    #[test]
+    fn transpose_swaps_rows_and_columns() {
+        let m = Matrix::new([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let transposed = m.transpose();
+
+        assert_eq!(transposed.get(0, 0), 1.0);
+        assert_eq!(transposed.get(1, 0), 2.0);
+        assert_eq!(transposed.get(2, 1), 6.0);
+    }
+}
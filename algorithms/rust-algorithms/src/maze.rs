@@ -0,0 +1,91 @@
+//! Breadth-first shortest-path search over a [`Grid`] of passable/blocked
+//! cells, guaranteeing the shortest path in an unweighted maze.
+
+use std::collections::{HashMap, VecDeque};
+
+use crate::grid::{Connectivity, Grid};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cell {
+    Open,
+    Wall,
+}
+
+type Point = (usize, usize);
+
+/// Finds the shortest path from `start` to `goal` over `grid`'s open
+/// cells, or `None` if no such path exists.
+pub fn shortest_path(grid: &Grid<Cell>, start: Point, goal: Point) -> Option<Vec<Point>> {
+    if *grid.get(start.0, start.1) == Cell::Wall || *grid.get(goal.0, goal.1) == Cell::Wall {
+        return None;
+    }
+
+    let mut came_from: HashMap<Point, Point> = HashMap::new();
+    let mut visited = HashMap::new();
+    visited.insert(start, true);
+
+    let mut queue = VecDeque::new();
+    queue.push_back(start);
+
+    while let Some(current) = queue.pop_front() {
+        if current == goal {
+            return Some(reconstruct_path(&came_from, start, goal));
+        }
+
+        for neighbor in grid.neighbors(current.0, current.1, Connectivity::Four) {
+            if *grid.get(neighbor.0, neighbor.1) == Cell::Wall {
+                continue;
+            }
+            if visited.insert(neighbor, true).is_none() {
+                came_from.insert(neighbor, current);
+                queue.push_back(neighbor);
+            }
+        }
+    }
+
+    None
+}
+
+fn reconstruct_path(came_from: &HashMap<Point, Point>, start: Point, goal: Point) -> Vec<Point> {
+    let mut path = vec![goal];
+    let mut current = goal;
+    while current != start {
+        current = came_from[&current];
+        path.push(current);
+    }
+    path.reverse();
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn maze_with_walls(walls: &[Point]) -> Grid<Cell> {
+        let mut grid = Grid::new(4, 4, Cell::Open);
+        for &(x, y) in walls {
+            grid.set(x, y, Cell::Wall);
+        }
+        grid
+    }
+
+    #[test]
+    fn finds_the_shortest_path_around_a_wall() {
+        // A wall splits the grid, leaving a single-column gap at x = 3.
+        let grid = maze_with_walls(&[(0, 1), (1, 1), (2, 1)]);
+
+        let path = shortest_path(&grid, (0, 0), (0, 3)).expect("a path exists");
+
+        assert_eq!(path.first(), Some(&(0, 0)));
+        assert_eq!(path.last(), Some(&(0, 3)));
+        assert!(!path.contains(&(0, 1)));
+        assert_eq!(path.len(), 10);
+    }
+
+    #[test]
+    fn returns_none_when_the_goal_is_fully_enclosed() {
+        let grid = maze_with_walls(&[(0, 1), (1, 1), (2, 1), (3, 1)]);
+
+        assert_eq!(shortest_path(&grid, (0, 0), (0, 3)), None);
+    }
+}
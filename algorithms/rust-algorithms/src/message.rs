@@ -0,0 +1,96 @@
+//! The `Message` enum from the pattern-matching notes, given real
+//! behavior: each variant can report whether it moves things, extract its
+//! text, and apply itself to an [`AppState`].
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Message {
+    Quit,
+    Move { x: i32, y: i32 },
+    Write(String),
+    ChangeColor(i32, i32, i32),
+}
+
+impl Message {
+    pub fn is_movement(&self) -> bool {
+        matches!(self, Message::Move { .. })
+    }
+
+    /// Returns the carried text, only for `Write`.
+    pub fn text(&self) -> Option<&str> {
+        match self {
+            Message::Write(text) => Some(text),
+            _ => None,
+        }
+    }
+
+    /// Mutates `state` according to this message's variant.
+    pub fn apply(&self, state: &mut AppState) {
+        match self {
+            Message::Quit => state.running = false,
+            Message::Move { x, y } => state.position = (*x, *y),
+            Message::Write(text) => state.text = text.clone(),
+            Message::ChangeColor(r, g, b) => state.color = (*r, *g, *b),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AppState {
+    pub position: (i32, i32),
+    pub text: String,
+    pub color: (i32, i32, i32),
+    pub running: bool,
+}
+
+impl AppState {
+    pub fn new() -> Self {
+        AppState {
+            position: (0, 0),
+            text: String::new(),
+            color: (0, 0, 0),
+            running: true,
+        }
+    }
+}
+
+impl Default for AppState {
+    fn default() -> Self {
+        AppState::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_movement_only_true_for_move() {
+        assert!(Message::Move { x: 1, y: 2 }.is_movement());
+        assert!(!Message::Quit.is_movement());
+        assert!(!Message::Write("hi".to_string()).is_movement());
+        assert!(!Message::ChangeColor(1, 2, 3).is_movement());
+    }
+
+    #[test]
+    fn text_only_returns_something_for_write() {
+        assert_eq!(Message::Write("hello".to_string()).text(), Some("hello"));
+        assert_eq!(Message::Quit.text(), None);
+    }
+
+    #[test]
+    fn apply_mutates_state_per_variant() {
+        let mut state = AppState::new();
+
+        Message::Move { x: 3, y: 4 }.apply(&mut state);
+        assert_eq!(state.position, (3, 4));
+
+        Message::Write("hi".to_string()).apply(&mut state);
+        assert_eq!(state.text, "hi");
+
+        Message::ChangeColor(10, 20, 30).apply(&mut state);
+        assert_eq!(state.color, (10, 20, 30));
+
+        Message::Quit.apply(&mut state);
+        assert!(!state.running);
+    }
+}
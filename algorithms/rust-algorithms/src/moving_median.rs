@@ -0,0 +1,65 @@
+//! A moving-median filter, complementing a moving average with a
+//! statistic that isn't skewed by a single outlier in the window.
+
+/// Computes the median of every `window`-sized slice of `data`, sliding
+/// one element at a time. The window is kept sorted so each median is a
+/// single indexing operation once the insert/remove is done.
+pub fn moving_median(data: &[f64], window: usize) -> Vec<f64> {
+    if window == 0 || window > data.len() {
+        return Vec::new();
+    }
+
+    let mut sorted: Vec<f64> = data[..window].to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).expect("NaN in input"));
+
+    let mut result = Vec::with_capacity(data.len() - window + 1);
+    result.push(median_of(&sorted));
+
+    for i in window..data.len() {
+        let leaving = data[i - window];
+        let position = sorted
+            .iter()
+            .position(|&v| v == leaving)
+            .expect("leaving value was inserted earlier");
+        sorted.remove(position);
+
+        let entering = data[i];
+        let insert_at = sorted.partition_point(|&v| v < entering);
+        sorted.insert(insert_at, entering);
+
+        result.push(median_of(&sorted));
+    }
+
+    result
+}
+
+fn median_of(sorted: &[f64]) -> f64 {
+    let mid = sorted.len() / 2;
+    if sorted.len() % 2 == 1 {
+        sorted[mid]
+    } else {
+        (sorted[mid - 1] + sorted[mid]) / 2.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn computes_the_moving_median_with_an_odd_window() {
+        let result = moving_median(&[5.0, 1.0, 3.0, 2.0, 4.0], 3);
+        assert_eq!(result, vec![3.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn computes_the_moving_median_with_an_even_window() {
+        let result = moving_median(&[1.0, 3.0, 2.0, 4.0], 2);
+        assert_eq!(result, vec![2.0, 2.5, 3.0]);
+    }
+
+    #[test]
+    fn returns_empty_when_the_window_exceeds_the_data_length() {
+        assert!(moving_median(&[1.0, 2.0], 5).is_empty());
+    }
+}
@@ -0,0 +1,111 @@
+//! Fibonacci and factorial, with checked (non-panicking) arithmetic and
+//! memoized variants built on [`Memoize`].
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// A small memoization cache mapping arguments to previously computed
+/// results, so recursive helpers can avoid redoing work.
+#[derive(Debug, Default)]
+pub struct Memoize<A, R> {
+    cache: HashMap<A, R>,
+}
+
+impl<A, R> Memoize<A, R>
+where
+    A: Eq + Hash + Clone,
+    R: Clone,
+{
+    pub fn new() -> Self {
+        Memoize {
+            cache: HashMap::new(),
+        }
+    }
+
+    /// Returns the cached result for `arg`, computing and storing it via
+    /// `f` on a miss.
+    pub fn get_or_insert_with(&mut self, arg: A, f: impl FnOnce(&A) -> R) -> R {
+        if let Some(hit) = self.cache.get(&arg) {
+            return hit.clone();
+        }
+        let result = f(&arg);
+        self.cache.insert(arg, result.clone());
+        result
+    }
+}
+
+/// Iterative Fibonacci using checked addition. Returns `None` on overflow
+/// instead of panicking or wrapping.
+pub fn fib_iter(n: u64) -> Option<u64> {
+    if n == 0 {
+        return Some(0);
+    }
+    let (mut a, mut b) = (0u64, 1u64);
+    for _ in 1..n {
+        let next = a.checked_add(b)?;
+        a = b;
+        b = next;
+    }
+    Some(b)
+}
+
+/// Iterative factorial using checked multiplication. Returns `None` on
+/// overflow instead of panicking or wrapping.
+pub fn factorial_iter(n: u64) -> Option<u64> {
+    let mut acc = 1u64;
+    for i in 2..=n {
+        acc = acc.checked_mul(i)?;
+    }
+    Some(acc)
+}
+
+/// Fibonacci memoized through a [`Memoize`] cache keyed by `n`.
+pub fn fib_memo(n: u64, memo: &mut Memoize<u64, Option<u64>>) -> Option<u64> {
+    if n < 2 {
+        return Some(n);
+    }
+    if let Some(hit) = memo.cache.get(&n) {
+        return *hit;
+    }
+    let result = fib_memo(n - 1, memo).and_then(|a| fib_memo(n - 2, memo).and_then(|b| a.checked_add(b)));
+    memo.cache.insert(n, result);
+    result
+}
+
+/// Factorial memoized through a [`Memoize`] cache keyed by `n`.
+pub fn factorial_memo(n: u64, memo: &mut Memoize<u64, Option<u64>>) -> Option<u64> {
+    if n < 2 {
+        return Some(1);
+    }
+    if let Some(hit) = memo.cache.get(&n) {
+        return *hit;
+    }
+    let result = factorial_memo(n - 1, memo).and_then(|prev| prev.checked_mul(n));
+    memo.cache.insert(n, result);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fib_iter_matches_known_value() {
+        assert_eq!(fib_iter(10), Some(55));
+    }
+
+    #[test]
+    fn factorial_iter_overflows_past_20() {
+        assert!(factorial_iter(20).is_some());
+        assert_eq!(factorial_iter(21), None);
+    }
+
+    #[test]
+    fn memoized_variants_agree_with_iterative() {
+        let mut fib_cache = Memoize::new();
+        let mut fact_cache = Memoize::new();
+        assert_eq!(fib_memo(10, &mut fib_cache), fib_iter(10));
+        assert_eq!(factorial_memo(20, &mut fact_cache), factorial_iter(20));
+        assert_eq!(factorial_memo(21, &mut fact_cache), factorial_iter(21));
+    }
+}
@@ -0,0 +1,102 @@
+//! A pool of reusable `T`s, avoiding repeated allocation for objects that
+//! are expensive to create but cheap to reset (buffers, scratch vectors).
+
+/// A pooled `T` on loan from an [`ObjectPool`]. Returns itself to the pool
+/// (after resetting) when dropped.
+pub struct PooledRef<'a, T> {
+    value: Option<T>,
+    pool: &'a mut ObjectPool<T>,
+}
+
+impl<T> std::ops::Deref for PooledRef<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.value.as_ref().expect("value is only taken on drop")
+    }
+}
+
+impl<T> std::ops::DerefMut for PooledRef<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        self.value.as_mut().expect("value is only taken on drop")
+    }
+}
+
+impl<T> Drop for PooledRef<'_, T> {
+    fn drop(&mut self) {
+        if let Some(mut value) = self.value.take() {
+            (self.pool.reset)(&mut value);
+            self.pool.free.push(value);
+        }
+    }
+}
+
+/// Hands out `T`s from a pool of previously-released instances, creating a
+/// new one via `factory` only when the pool is empty.
+pub struct ObjectPool<T> {
+    free: Vec<T>,
+    factory: Box<dyn FnMut() -> T>,
+    reset: Box<dyn FnMut(&mut T)>,
+}
+
+impl<T> ObjectPool<T> {
+    pub fn new(factory: impl FnMut() -> T + 'static, reset: impl FnMut(&mut T) + 'static) -> Self {
+        ObjectPool {
+            free: Vec::new(),
+            factory: Box::new(factory),
+            reset: Box::new(reset),
+        }
+    }
+
+    /// Takes an object from the pool, creating one if none are free.
+    pub fn acquire(&mut self) -> PooledRef<'_, T> {
+        let value = self.free.pop().unwrap_or_else(|| (self.factory)());
+        PooledRef {
+            value: Some(value),
+            pool: self,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    #[test]
+    fn dropping_a_pooled_ref_makes_it_available_for_reuse() {
+        let created = Rc::new(Cell::new(0));
+        let created_for_factory = Rc::clone(&created);
+
+        // Each created object carries the generation it was created at,
+        // so the test can tell reuse apart from a fresh allocation.
+        let mut pool = ObjectPool::new(
+            move || {
+                created_for_factory.set(created_for_factory.get() + 1);
+                created_for_factory.get()
+            },
+            |_generation: &mut u32| {}, // no-op reset
+        );
+
+        let first = pool.acquire();
+        let first_generation = *first;
+        drop(first);
+
+        let second = pool.acquire();
+        assert_eq!(*second, first_generation);
+        assert_eq!(created.get(), 1, "no second allocation should have happened");
+    }
+
+    #[test]
+    fn reset_runs_before_an_object_is_reused() {
+        let mut pool = ObjectPool::new(Vec::<i32>::new, |v: &mut Vec<i32>| v.clear());
+
+        let mut first = pool.acquire();
+        first.push(42);
+        drop(first);
+
+        let second = pool.acquire();
+        assert!(second.is_empty());
+    }
+}
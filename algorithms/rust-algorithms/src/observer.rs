@@ -0,0 +1,103 @@
+//! A classic Observer/Subject pattern using trait objects, layered over a
+//! `Mutex`-guarded piece of shared state.
+
+use std::sync::{Arc, Mutex};
+
+/// Something that reacts when a [`Subject`]'s value changes.
+pub trait Observer {
+    fn on_change(&self, value: i32);
+}
+
+/// Holds a piece of `Arc<Mutex<i32>>` state and a list of observers to
+/// notify whenever that state changes.
+pub struct Subject {
+    state: Arc<Mutex<i32>>,
+    observers: Vec<Box<dyn Observer>>,
+}
+
+impl Subject {
+    pub fn new(initial: i32) -> Self {
+        Subject {
+            state: Arc::new(Mutex::new(initial)),
+            observers: Vec::new(),
+        }
+    }
+
+    pub fn attach(&mut self, observer: Box<dyn Observer>) {
+        self.observers.push(observer);
+    }
+
+    /// Updates the shared state and notifies every attached observer with
+    /// the new value.
+    pub fn set(&mut self, value: i32) {
+        *self.state.lock().expect("state mutex poisoned") = value;
+        self.notify_all();
+    }
+
+    fn notify_all(&self) {
+        let value = *self.state.lock().expect("state mutex poisoned");
+        for observer in &self.observers {
+            observer.on_change(value);
+        }
+    }
+}
+
+/// An observer that appends every value it sees to a shared log.
+pub struct LoggingObserver {
+    log: Arc<Mutex<Vec<i32>>>,
+}
+
+impl LoggingObserver {
+    pub fn new(log: Arc<Mutex<Vec<i32>>>) -> Self {
+        LoggingObserver { log }
+    }
+}
+
+impl Observer for LoggingObserver {
+    fn on_change(&self, value: i32) {
+        self.log.lock().expect("log mutex poisoned").push(value);
+    }
+}
+
+/// An observer that only records values strictly above a threshold.
+pub struct ThresholdObserver {
+    threshold: i32,
+    hits: Arc<Mutex<Vec<i32>>>,
+}
+
+impl ThresholdObserver {
+    pub fn new(threshold: i32, hits: Arc<Mutex<Vec<i32>>>) -> Self {
+        ThresholdObserver { threshold, hits }
+    }
+}
+
+impl Observer for ThresholdObserver {
+    fn on_change(&self, value: i32) {
+        if value > self.threshold {
+            self.hits.lock().expect("hits mutex poisoned").push(value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn observers_receive_every_change() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let hits = Arc::new(Mutex::new(Vec::new()));
+
+        let mut subject = Subject::new(0);
+        subject.attach(Box::new(LoggingObserver::new(Arc::clone(&log))));
+        subject.attach(Box::new(ThresholdObserver::new(10, Arc::clone(&hits))));
+
+        subject.set(3);
+        subject.set(15);
+        subject.set(7);
+        subject.set(42);
+
+        assert_eq!(*log.lock().unwrap(), vec![3, 15, 7, 42]);
+        assert_eq!(*hits.lock().unwrap(), vec![15, 42]);
+    }
+}
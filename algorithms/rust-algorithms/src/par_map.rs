@@ -0,0 +1,56 @@
+//! A parallel map over a slice using scoped threads, so `f` can borrow from
+//! its environment without needing `Arc` to share it across threads.
+
+use std::thread;
+
+/// Splits `slice` into roughly-equal chunks, maps `f` over each chunk on
+/// its own scoped thread, and collects the results back in original order.
+pub fn par_map<T, U, F>(slice: &[T], f: F) -> Vec<U>
+where
+    T: Sync,
+    U: Send,
+    F: Fn(&T) -> U + Sync,
+{
+    if slice.is_empty() {
+        return Vec::new();
+    }
+
+    let thread_count = thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1)
+        .min(slice.len());
+    let chunk_size = slice.len().div_ceil(thread_count);
+
+    thread::scope(|scope| {
+        let handles: Vec<_> = slice
+            .chunks(chunk_size)
+            .map(|chunk| scope.spawn(|| chunk.iter().map(&f).collect::<Vec<U>>()))
+            .collect();
+
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().expect("worker thread panicked"))
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn squares_a_thousand_numbers_matching_the_sequential_order() {
+        let input: Vec<i64> = (0..1000).collect();
+
+        let parallel: Vec<i64> = par_map(&input, |&x| x * x);
+        let sequential: Vec<i64> = input.iter().map(|&x| x * x).collect();
+
+        assert_eq!(parallel, sequential);
+    }
+
+    #[test]
+    fn an_empty_slice_maps_to_an_empty_vec() {
+        let input: Vec<i32> = Vec::new();
+        assert_eq!(par_map(&input, |&x| x), Vec::<i32>::new());
+    }
+}
@@ -0,0 +1,71 @@
+//! A `Result`-returning parse trait that keeps the offending input snippet
+//! and a reason around, generalizing the ad-hoc parsing scattered across
+//! the config/CSV examples.
+
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub input: String,
+    pub reason: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "failed to parse `{}`: {}", self.input, self.reason)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+pub trait ParseFrom<'a>: Sized {
+    fn parse_from(s: &'a str) -> Result<Self, ParseError>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ipv4 {
+    pub octets: [u8; 4],
+}
+
+impl<'a> ParseFrom<'a> for Ipv4 {
+    fn parse_from(s: &'a str) -> Result<Self, ParseError> {
+        let error = |reason: String| ParseError {
+            input: s.to_string(),
+            reason,
+        };
+
+        let parts: Vec<&str> = s.split('.').collect();
+        if parts.len() != 4 {
+            return Err(error(format!("expected 4 octets, found {}", parts.len())));
+        }
+
+        let mut octets = [0u8; 4];
+        for (i, part) in parts.iter().enumerate() {
+            octets[i] = part
+                .parse::<u16>()
+                .ok()
+                .filter(|&value| value <= 255)
+                .ok_or_else(|| error(format!("octet `{part}` is not a valid 0-255 value")))?
+                as u8;
+        }
+
+        Ok(Ipv4 { octets })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_a_valid_ipv4_address() {
+        let address = Ipv4::parse_from("192.168.0.1").expect("valid address");
+        assert_eq!(address.octets, [192, 168, 0, 1]);
+    }
+
+    #[test]
+    fn an_out_of_range_octet_errors_with_the_offending_value() {
+        let err = Ipv4::parse_from("256.0.0.1").unwrap_err();
+        assert!(err.reason.contains("256"));
+    }
+}
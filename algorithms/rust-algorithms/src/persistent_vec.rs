@@ -0,0 +1,175 @@
+//! An immutable, structurally-shared vector backed by a 32-ary trie
+//! (Clojure/Scala-style), in the spirit of [`crate::cow_tree`]: `push`
+//! and `set` return a new vector that shares every untouched subtree
+//! with the original via `Rc`, giving O(log32 n) access.
+
+use std::rc::Rc;
+
+const BITS: u32 = 5;
+const WIDTH: usize = 1 << BITS;
+const MASK: usize = WIDTH - 1;
+
+enum Node<T> {
+    Branch(Vec<Rc<Node<T>>>),
+    Leaf(Vec<T>),
+}
+
+#[derive(Clone)]
+pub struct PersistentVec<T> {
+    root: Option<Rc<Node<T>>>,
+    len: usize,
+    shift: u32,
+}
+
+impl<T: Clone> PersistentVec<T> {
+    pub fn new() -> Self {
+        PersistentVec { root: None, len: 0, shift: 0 }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.len {
+            return None;
+        }
+
+        let mut node = self.root.as_ref().expect("len > 0 implies a root");
+        let mut shift = self.shift;
+        loop {
+            match node.as_ref() {
+                Node::Branch(children) => {
+                    node = &children[(index >> shift) & MASK];
+                    shift -= BITS;
+                }
+                Node::Leaf(values) => return values.get(index & MASK),
+            }
+        }
+    }
+
+    /// Returns a new vector with `value` appended, leaving `self` and
+    /// every subtree not on the path to the new slot unchanged.
+    pub fn push(&self, value: T) -> PersistentVec<T> {
+        let index = self.len;
+
+        let Some(root) = &self.root else {
+            return PersistentVec { root: Some(push_node(None, 0, index, value)), len: 1, shift: 0 };
+        };
+
+        // The current tree is full once `index` reaches its capacity, so
+        // a new root level is needed to make room for the next slot.
+        let capacity = 1usize << (self.shift + BITS);
+        if index == capacity {
+            let grown_root = Rc::new(Node::Branch(vec![Rc::clone(root)]));
+            let shift = self.shift + BITS;
+            PersistentVec { root: Some(push_node(Some(&grown_root), shift, index, value)), len: index + 1, shift }
+        } else {
+            PersistentVec {
+                root: Some(push_node(Some(root), self.shift, index, value)),
+                len: index + 1,
+                shift: self.shift,
+            }
+        }
+    }
+
+    /// Returns a new vector with the value at `index` replaced.
+    pub fn set(&self, index: usize, value: T) -> PersistentVec<T> {
+        assert!(index < self.len, "index {index} out of bounds for length {}", self.len);
+        let root = self.root.as_ref().expect("len > 0 implies a root");
+        PersistentVec { root: Some(set_node(root, self.shift, index, value)), len: self.len, shift: self.shift }
+    }
+}
+
+impl<T: Clone> Default for PersistentVec<T> {
+    fn default() -> Self {
+        PersistentVec::new()
+    }
+}
+
+/// Appends `value` at the slot for `index` (always one past the current
+/// end), walking down the bits of `index` to find or grow the path to a
+/// leaf, path-copying only the nodes it visits.
+fn push_node<T: Clone>(node: Option<&Rc<Node<T>>>, shift: u32, index: usize, value: T) -> Rc<Node<T>> {
+    if shift == 0 {
+        let mut values = match node.map(Rc::as_ref) {
+            Some(Node::Leaf(values)) => values.clone(),
+            _ => Vec::new(),
+        };
+        values.push(value);
+        Rc::new(Node::Leaf(values))
+    } else {
+        let child_index = (index >> shift) & MASK;
+        let mut children = match node.map(Rc::as_ref) {
+            Some(Node::Branch(children)) => children.clone(),
+            _ => Vec::new(),
+        };
+        let existing_child = children.get(child_index);
+        let new_child = push_node(existing_child, shift - BITS, index, value);
+        if child_index < children.len() {
+            children[child_index] = new_child;
+        } else {
+            children.push(new_child);
+        }
+        Rc::new(Node::Branch(children))
+    }
+}
+
+fn set_node<T: Clone>(node: &Rc<Node<T>>, shift: u32, index: usize, value: T) -> Rc<Node<T>> {
+    match node.as_ref() {
+        Node::Leaf(values) => {
+            let mut values = values.clone();
+            values[index & MASK] = value;
+            Rc::new(Node::Leaf(values))
+        }
+        Node::Branch(children) => {
+            let mut children = children.clone();
+            let child_index = (index >> shift) & MASK;
+            children[child_index] = set_node(&children[child_index], shift - BITS, index, value);
+            Rc::new(Node::Branch(children))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn push_leaves_the_base_vector_unchanged() {
+        let base = PersistentVec::new().push(1).push(2).push(3);
+        let extended = base.push(4);
+
+        assert_eq!(base.len(), 3);
+        assert_eq!(extended.len(), 4);
+        assert_eq!(base.get(3), None);
+        assert_eq!(extended.get(3), Some(&4));
+    }
+
+    #[test]
+    fn set_leaves_the_base_vector_unchanged() {
+        let base = PersistentVec::new().push(1).push(2).push(3);
+        let updated = base.set(1, 20);
+
+        assert_eq!(base.get(1), Some(&2));
+        assert_eq!(updated.get(1), Some(&20));
+    }
+
+    #[test]
+    fn random_access_reads_are_correct_across_ten_thousand_elements() {
+        let mut vector = PersistentVec::new();
+        for i in 0..10_000 {
+            vector = vector.push(i);
+        }
+
+        assert_eq!(vector.len(), 10_000);
+        for i in (0..10_000).step_by(37) {
+            assert_eq!(vector.get(i), Some(&i));
+        }
+        assert_eq!(vector.get(10_000), None);
+    }
+}
@@ -0,0 +1,174 @@
+//! A piece table for text-editor-style edits: the document is
+//! represented as a sequence of pieces referencing spans of an
+//! immutable original buffer or an append-only edit buffer, so
+//! `insert`/`delete` cost O(pieces) rather than copying the whole text.
+
+use std::fmt::{self, Write};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Source {
+    Original,
+    Added,
+}
+
+#[derive(Clone, Copy)]
+struct Piece {
+    source: Source,
+    start: usize,
+    len: usize,
+}
+
+pub struct PieceTable {
+    original: Vec<char>,
+    added: Vec<char>,
+    pieces: Vec<Piece>,
+}
+
+impl PieceTable {
+    pub fn new(text: &str) -> Self {
+        let original: Vec<char> = text.chars().collect();
+        let len = original.len();
+        PieceTable {
+            original,
+            added: Vec::new(),
+            pieces: if len == 0 {
+                Vec::new()
+            } else {
+                vec![Piece { source: Source::Original, start: 0, len }]
+            },
+        }
+    }
+
+    pub fn char_len(&self) -> usize {
+        self.pieces.iter().map(|piece| piece.len).sum()
+    }
+
+    /// Inserts `text` at char index `pos`, splitting the piece that
+    /// spans `pos` (if any) around a new piece pointing into the append
+    /// buffer.
+    pub fn insert(&mut self, pos: usize, text: &str) {
+        if text.is_empty() {
+            return;
+        }
+
+        let start = self.added.len();
+        self.added.extend(text.chars());
+        let new_piece = Piece { source: Source::Added, start, len: text.chars().count() };
+
+        let (piece_index, offset) = self.locate(pos);
+        match piece_index {
+            Some(index) if offset == 0 => self.pieces.insert(index, new_piece),
+            Some(index) => {
+                let piece = self.pieces[index];
+                let before = Piece { start: piece.start, len: offset, ..piece };
+                let after = Piece { start: piece.start + offset, len: piece.len - offset, ..piece };
+                self.pieces.splice(index..=index, [before, new_piece, after]);
+            }
+            None => self.pieces.push(new_piece),
+        }
+    }
+
+    /// Deletes `len` chars starting at char index `pos`, trimming or
+    /// splitting pieces as needed without touching the underlying buffers.
+    pub fn delete(&mut self, pos: usize, len: usize) {
+        if len == 0 {
+            return;
+        }
+        let end = pos + len;
+
+        let mut result = Vec::with_capacity(self.pieces.len());
+        let mut cursor = 0;
+        for piece in &self.pieces {
+            let piece_start = cursor;
+            let piece_end = cursor + piece.len;
+            cursor = piece_end;
+
+            let delete_start = pos.max(piece_start);
+            let delete_end = end.min(piece_end);
+            if delete_start >= delete_end {
+                result.push(*piece);
+                continue;
+            }
+
+            if delete_start > piece_start {
+                result.push(Piece {
+                    start: piece.start,
+                    len: delete_start - piece_start,
+                    ..*piece
+                });
+            }
+            if delete_end < piece_end {
+                result.push(Piece {
+                    start: piece.start + (delete_end - piece_start),
+                    len: piece_end - delete_end,
+                    ..*piece
+                });
+            }
+        }
+        self.pieces = result;
+    }
+
+    /// Finds which piece contains char index `pos` and the offset within
+    /// it, or `None` when `pos` is at (or past) the end of the document.
+    fn locate(&self, pos: usize) -> (Option<usize>, usize) {
+        let mut cursor = 0;
+        for (index, piece) in self.pieces.iter().enumerate() {
+            if pos < cursor + piece.len {
+                return (Some(index), pos - cursor);
+            }
+            cursor += piece.len;
+        }
+        (None, 0)
+    }
+
+    fn buffer(&self, source: Source) -> &[char] {
+        match source {
+            Source::Original => &self.original,
+            Source::Added => &self.added,
+        }
+    }
+}
+
+impl fmt::Display for PieceTable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for piece in &self.pieces {
+            let buffer = self.buffer(piece.source);
+            for &c in &buffer[piece.start..piece.start + piece.len] {
+                f.write_char(c)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_and_delete_match_the_equivalent_naive_string_edits() {
+        let mut table = PieceTable::new("hello world");
+        let mut naive = String::from("hello world");
+
+        table.insert(5, ", there");
+        naive.insert_str(5, ", there");
+        assert_eq!(table.to_string(), naive);
+
+        table.delete(0, 6);
+        naive.replace_range(0..6, "");
+        assert_eq!(table.to_string(), naive);
+
+        table.insert(table.char_len(), "!");
+        naive.push('!');
+        assert_eq!(table.to_string(), naive);
+    }
+
+    #[test]
+    fn char_len_tracks_the_document_length() {
+        let mut table = PieceTable::new("abc");
+        table.insert(1, "XY");
+        assert_eq!(table.char_len(), 5);
+        table.delete(1, 2);
+        assert_eq!(table.char_len(), 3);
+    }
+}
@@ -0,0 +1,88 @@
+//! A `source -> transform -> sink` pipeline where each stage runs on its
+//! own thread, connected by bounded `mpsc` channels so a slow downstream
+//! stage applies backpressure to the ones feeding it.
+
+use std::sync::mpsc::{self, Receiver};
+use std::thread::{self, JoinHandle};
+
+/// The output end of a chain of pipeline stages. Built up with
+/// [`Pipeline::source`] and [`Pipeline::stage`], and drained with
+/// [`Pipeline::sink`].
+pub struct Pipeline<T> {
+    receiver: Receiver<T>,
+    handles: Vec<JoinHandle<()>>,
+}
+
+impl<T: Send + 'static> Pipeline<T> {
+    /// Starts a pipeline by feeding `source` into a bounded channel of
+    /// `capacity` slots from its own thread.
+    pub fn source<S>(source: S, capacity: usize) -> Self
+    where
+        S: IntoIterator<Item = T> + Send + 'static,
+    {
+        let (sender, receiver) = mpsc::sync_channel(capacity);
+        let handle = thread::spawn(move || {
+            for item in source {
+                if sender.send(item).is_err() {
+                    break;
+                }
+            }
+        });
+        Pipeline {
+            receiver,
+            handles: vec![handle],
+        }
+    }
+
+    /// Adds a transform stage, running `f` on its own thread and forwarding
+    /// its output through a new bounded channel of `capacity` slots.
+    pub fn stage<U, F>(self, capacity: usize, f: F) -> Pipeline<U>
+    where
+        U: Send + 'static,
+        F: Fn(T) -> U + Send + 'static,
+    {
+        let (sender, receiver) = mpsc::sync_channel(capacity);
+        let upstream = self.receiver;
+        let handle = thread::spawn(move || {
+            for item in upstream {
+                if sender.send(f(item)).is_err() {
+                    break;
+                }
+            }
+        });
+        let mut handles = self.handles;
+        handles.push(handle);
+        Pipeline { receiver, handles }
+    }
+
+    /// Drains the pipeline on the calling thread, passing each item to
+    /// `sink`, then waits for every upstream stage to finish.
+    pub fn sink<F: FnMut(T)>(self, mut sink: F) {
+        for item in self.receiver {
+            sink(item);
+        }
+        for handle in self.handles {
+            let _ = handle.join();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn doubles_a_thousand_integers_through_a_bounded_pipeline() {
+        let results = Arc::new(Mutex::new(Vec::new()));
+        let collected = Arc::clone(&results);
+
+        Pipeline::source(0..1000, 8)
+            .stage(8, |x| x * 2)
+            .sink(move |x| collected.lock().unwrap().push(x));
+
+        let results = results.lock().unwrap();
+        assert_eq!(results.len(), 1000);
+        assert!(results.iter().enumerate().all(|(i, &value)| value == i as i32 * 2));
+    }
+}
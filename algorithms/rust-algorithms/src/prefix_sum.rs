@@ -0,0 +1,51 @@
+//! A static prefix-sum structure answering range-sum queries in O(1)
+//! after an O(n) build, for data that doesn't change between queries.
+//! See [`crate::fenwick_tree`] for a mutable variant.
+
+pub struct PrefixSum {
+    cumulative: Vec<i64>,
+}
+
+impl PrefixSum {
+    pub fn new(data: &[i64]) -> Self {
+        let mut cumulative = Vec::with_capacity(data.len() + 1);
+        cumulative.push(0);
+        for &value in data {
+            cumulative.push(cumulative.last().unwrap() + value);
+        }
+        PrefixSum { cumulative }
+    }
+
+    /// The sum of `data[lo..=hi]`, or `None` if the range is out of
+    /// bounds or empty.
+    pub fn range_sum(&self, lo: usize, hi: usize) -> Option<i64> {
+        if lo > hi || hi + 1 >= self.cumulative.len() {
+            return None;
+        }
+        Some(self.cumulative[hi + 1] - self.cumulative[lo])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn range_sum_matches_a_direct_sum() {
+        let prefix_sum = PrefixSum::new(&[1, 2, 3, 4]);
+        assert_eq!(prefix_sum.range_sum(1, 3), Some(9));
+    }
+
+    #[test]
+    fn a_single_element_range_returns_that_element() {
+        let prefix_sum = PrefixSum::new(&[1, 2, 3, 4]);
+        assert_eq!(prefix_sum.range_sum(2, 2), Some(3));
+    }
+
+    #[test]
+    fn an_out_of_range_query_returns_none() {
+        let prefix_sum = PrefixSum::new(&[1, 2, 3, 4]);
+        assert_eq!(prefix_sum.range_sum(2, 10), None);
+        assert_eq!(prefix_sum.range_sum(3, 1), None);
+    }
+}
@@ -0,0 +1,13 @@
+//! A `PrettyPrint` trait for producing indented, human-readable renderings
+//! of nested structures, since `{:#?}` shows Rust's internal shape rather
+//! than a domain-appropriate one.
+
+pub trait PrettyPrint {
+    /// Renders `self` at the given indentation depth (each level adds two
+    /// spaces), with no trailing newline.
+    fn pretty(&self, indent: usize) -> String;
+}
+
+pub(crate) fn pad(indent: usize) -> String {
+    "  ".repeat(indent)
+}
@@ -0,0 +1,63 @@
+//! A latency-style quantile estimator that keeps memory constant by
+//! recording into a [`ReservoirSampler`] rather than retaining every
+//! value.
+
+use crate::reservoir_sampler::ReservoirSampler;
+use crate::rng::Rng;
+
+/// Estimates quantiles of a stream of `f64`s from a bounded random
+/// sample of the values seen so far.
+pub struct Quantiles<R: Rng> {
+    reservoir: ReservoirSampler<f64, R>,
+}
+
+impl<R: Rng> Quantiles<R> {
+    pub fn new(sample_size: usize, rng: R) -> Self {
+        Quantiles {
+            reservoir: ReservoirSampler::new(sample_size, rng),
+        }
+    }
+
+    pub fn record(&mut self, value: f64) {
+        self.reservoir.add(value);
+    }
+
+    /// The estimated value at quantile `q` (`0.0` = minimum, `1.0` =
+    /// maximum of the sample), or `None` if nothing has been recorded.
+    pub fn quantile(&self, q: f64) -> Option<f64> {
+        let mut sample = self.reservoir.sample().to_vec();
+        if sample.is_empty() {
+            return None;
+        }
+
+        sample.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        let index = ((q * (sample.len() - 1) as f64).round() as usize).min(sample.len() - 1);
+        Some(sample[index])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::Lcg;
+
+    #[test]
+    fn quantile_is_none_before_any_data() {
+        let quantiles = Quantiles::new(500, Lcg::new(1));
+        assert_eq!(quantiles.quantile(0.5), None);
+    }
+
+    #[test]
+    fn quantiles_of_a_uniform_stream_land_near_their_expected_value() {
+        let mut quantiles = Quantiles::new(500, Lcg::new(1));
+        for i in 0..1000 {
+            quantiles.record(i as f64);
+        }
+
+        let median = quantiles.quantile(0.5).unwrap();
+        let p90 = quantiles.quantile(0.9).unwrap();
+
+        assert!((median - 500.0).abs() < 50.0, "median was {median}");
+        assert!((p90 - 900.0).abs() < 50.0, "p90 was {p90}");
+    }
+}
@@ -0,0 +1,134 @@
+//! A sliding-window rate limiter, keyed by an arbitrary string (e.g. a
+//! client id or API key). The clock is injectable, mirroring
+//! [`crate::retry::Sleeper`]'s approach to keeping time-dependent logic
+//! testable.
+
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, Instant};
+
+/// Supplies the current time. `SystemClock` is the production
+/// implementation; tests substitute a clock they can advance manually.
+pub trait Clock {
+    fn now(&self) -> Instant;
+}
+
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// Allows at most `limit` events per `window` per key, using a sliding
+/// window of timestamps rather than fixed buckets.
+pub struct RateLimiter<C: Clock = SystemClock> {
+    limit: usize,
+    window: Duration,
+    clock: C,
+    history: HashMap<String, VecDeque<Instant>>,
+}
+
+impl RateLimiter<SystemClock> {
+    pub fn new(limit: usize, window: Duration) -> Self {
+        RateLimiter::with_clock(limit, window, SystemClock)
+    }
+}
+
+impl<C: Clock> RateLimiter<C> {
+    pub fn with_clock(limit: usize, window: Duration, clock: C) -> Self {
+        RateLimiter {
+            limit,
+            window,
+            clock,
+            history: HashMap::new(),
+        }
+    }
+
+    /// Returns `true` and records the event if `key` is under its limit
+    /// for the current window; otherwise returns `false` without
+    /// recording anything.
+    pub fn allow(&mut self, key: &str) -> bool {
+        let now = self.clock.now();
+        let window = self.window;
+        let timestamps = self.history.entry(key.to_string()).or_default();
+        while let Some(&oldest) = timestamps.front() {
+            if now.duration_since(oldest) > window {
+                timestamps.pop_front();
+            } else {
+                break;
+            }
+        }
+
+        if timestamps.len() < self.limit {
+            timestamps.push_back(now);
+            true
+        } else {
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    /// A clock whose `now()` starts at an arbitrary fixed instant and only
+    /// moves forward when `advance` is called.
+    struct MockClock {
+        now: Cell<Instant>,
+    }
+
+    impl MockClock {
+        fn new() -> Self {
+            MockClock {
+                now: Cell::new(Instant::now()),
+            }
+        }
+
+        fn advance(&self, by: Duration) {
+            self.now.set(self.now.get() + by);
+        }
+    }
+
+    impl Clock for &MockClock {
+        fn now(&self) -> Instant {
+            self.now.get()
+        }
+    }
+
+    #[test]
+    fn allows_up_to_the_limit_then_denies_within_the_window() {
+        let clock = MockClock::new();
+        let mut limiter = RateLimiter::with_clock(3, Duration::from_secs(60), &clock);
+
+        assert!(limiter.allow("client-a"));
+        assert!(limiter.allow("client-a"));
+        assert!(limiter.allow("client-a"));
+        assert!(!limiter.allow("client-a"));
+    }
+
+    #[test]
+    fn allows_again_once_the_oldest_event_falls_outside_the_window() {
+        let clock = MockClock::new();
+        let mut limiter = RateLimiter::with_clock(2, Duration::from_secs(10), &clock);
+
+        assert!(limiter.allow("client-a"));
+        assert!(limiter.allow("client-a"));
+        assert!(!limiter.allow("client-a"));
+
+        clock.advance(Duration::from_secs(11));
+        assert!(limiter.allow("client-a"));
+    }
+
+    #[test]
+    fn tracks_limits_independently_per_key() {
+        let clock = MockClock::new();
+        let mut limiter = RateLimiter::with_clock(1, Duration::from_secs(60), &clock);
+
+        assert!(limiter.allow("client-a"));
+        assert!(limiter.allow("client-b"));
+        assert!(!limiter.allow("client-a"));
+    }
+}
@@ -0,0 +1,218 @@
+//! A tiny regex engine over literals, `.`, `*`, `+`, `?`, and the `^`/`$`
+//! anchors, compiled to an NFA via Thompson's construction and matched
+//! with a Pike-style thread simulation so matching stays linear in the
+//! length of the text, regardless of how many `*`/`+` a pattern has.
+
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RegexError {
+    /// A `*`, `+`, or `?` appeared with no preceding atom to quantify.
+    DanglingQuantifier,
+}
+
+impl fmt::Display for RegexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "quantifier with no preceding atom")
+    }
+}
+
+impl std::error::Error for RegexError {}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum AtomKind {
+    Char(char),
+    Any,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Quantifier {
+    None,
+    Star,
+    Plus,
+    Question,
+}
+
+struct Atom {
+    kind: AtomKind,
+    quantifier: Quantifier,
+}
+
+/// A single NFA instruction. Anything other than `Split`/`Jmp`/`Match`
+/// falls through to the next instruction on success.
+#[derive(Debug, Clone, Copy)]
+enum Inst {
+    Char(char),
+    Any,
+    Split(usize, usize),
+    Jmp(usize),
+    Match,
+}
+
+#[derive(Debug)]
+pub struct Regex {
+    program: Vec<Inst>,
+    anchored_end: bool,
+}
+
+impl Regex {
+    pub fn new(pattern: &str) -> Result<Self, RegexError> {
+        let anchored_start = pattern.starts_with('^');
+        let body = pattern.strip_prefix('^').unwrap_or(pattern);
+        let anchored_end = body.ends_with('$');
+        let body = body.strip_suffix('$').unwrap_or(body);
+
+        let atoms = parse_atoms(body)?;
+
+        let mut program = Vec::new();
+        if !anchored_start {
+            // A leading `.*` loop lets the same linear scan try every
+            // start offset, instead of re-running the NFA per offset.
+            program.push(Inst::Split(1, 3));
+            program.push(Inst::Any);
+            program.push(Inst::Jmp(0));
+        }
+        compile_atoms(&atoms, &mut program);
+        program.push(Inst::Match);
+
+        Ok(Regex { program, anchored_end })
+    }
+
+    pub fn is_match(&self, text: &str) -> bool {
+        let chars: Vec<char> = text.chars().collect();
+
+        let mut current = self.thread_set(&[0]);
+        for &c in &chars {
+            if !self.anchored_end && current.iter().any(|&pc| matches!(self.program[pc], Inst::Match)) {
+                return true;
+            }
+
+            let mut next_pcs = Vec::new();
+            for &pc in &current {
+                match self.program[pc] {
+                    Inst::Char(expected) if expected == c => next_pcs.push(pc + 1),
+                    Inst::Any => next_pcs.push(pc + 1),
+                    _ => {}
+                }
+            }
+            current = self.thread_set(&next_pcs);
+            if current.is_empty() {
+                return false;
+            }
+        }
+
+        current.iter().any(|&pc| matches!(self.program[pc], Inst::Match))
+    }
+
+    /// The epsilon-closure of `roots`: every state reachable without
+    /// consuming a character, deduplicated so a step never revisits the
+    /// same instruction twice.
+    fn thread_set(&self, roots: &[usize]) -> Vec<usize> {
+        let mut visited = vec![false; self.program.len()];
+        let mut result = Vec::new();
+        let mut stack: Vec<usize> = roots.to_vec();
+        while let Some(pc) = stack.pop() {
+            if visited[pc] {
+                continue;
+            }
+            visited[pc] = true;
+            match self.program[pc] {
+                Inst::Jmp(target) => stack.push(target),
+                Inst::Split(a, b) => {
+                    stack.push(a);
+                    stack.push(b);
+                }
+                _ => result.push(pc),
+            }
+        }
+        result
+    }
+}
+
+fn parse_atoms(body: &str) -> Result<Vec<Atom>, RegexError> {
+    let mut atoms: Vec<Atom> = Vec::new();
+    for c in body.chars() {
+        match c {
+            '*' | '+' | '?' => {
+                let atom = atoms.last_mut().ok_or(RegexError::DanglingQuantifier)?;
+                atom.quantifier = match c {
+                    '*' => Quantifier::Star,
+                    '+' => Quantifier::Plus,
+                    _ => Quantifier::Question,
+                };
+            }
+            '.' => atoms.push(Atom { kind: AtomKind::Any, quantifier: Quantifier::None }),
+            _ => atoms.push(Atom { kind: AtomKind::Char(c), quantifier: Quantifier::None }),
+        }
+    }
+    Ok(atoms)
+}
+
+fn atom_inst(atom: &Atom) -> Inst {
+    match atom.kind {
+        AtomKind::Char(c) => Inst::Char(c),
+        AtomKind::Any => Inst::Any,
+    }
+}
+
+fn compile_atoms(atoms: &[Atom], program: &mut Vec<Inst>) {
+    for atom in atoms {
+        match atom.quantifier {
+            Quantifier::None => program.push(atom_inst(atom)),
+            Quantifier::Star => {
+                let split = program.len();
+                program.push(Inst::Split(split + 1, 0));
+                program.push(atom_inst(atom));
+                program.push(Inst::Jmp(split));
+                let after = program.len();
+                program[split] = Inst::Split(split + 1, after);
+            }
+            Quantifier::Plus => {
+                let body = program.len();
+                program.push(atom_inst(atom));
+                program.push(Inst::Split(body, 0));
+                let after = program.len();
+                program[body + 1] = Inst::Split(body, after);
+            }
+            Quantifier::Question => {
+                let split = program.len();
+                program.push(Inst::Split(split + 1, 0));
+                program.push(atom_inst(atom));
+                let after = program.len();
+                program[split] = Inst::Split(split + 1, after);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn anchored_pattern_matches_the_expected_strings() {
+        let re = Regex::new("^a.*b$").unwrap();
+        assert!(re.is_match("axxb"));
+        assert!(!re.is_match("axx"));
+    }
+
+    #[test]
+    fn unanchored_pattern_matches_as_a_substring() {
+        let re = Regex::new("ab+c").unwrap();
+        assert!(re.is_match("xxabbbcxx"));
+        assert!(!re.is_match("xxacxx"));
+    }
+
+    #[test]
+    fn question_mark_makes_an_atom_optional() {
+        let re = Regex::new("^colou?r$").unwrap();
+        assert!(re.is_match("color"));
+        assert!(re.is_match("colour"));
+        assert!(!re.is_match("colouur"));
+    }
+
+    #[test]
+    fn a_dangling_quantifier_is_rejected() {
+        assert_eq!(Regex::new("*abc").unwrap_err(), RegexError::DanglingQuantifier);
+    }
+}
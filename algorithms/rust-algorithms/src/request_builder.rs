@@ -0,0 +1,173 @@
+//! A typestate builder for [`Request`], combined with runtime
+//! validation: the type parameters (reusing [`crate::config`]'s
+//! `Missing`/`Set` markers) make omitting `method()` or `url()` a
+//! compile error, while `build()` still returns a `Result` for checks
+//! that can only happen at runtime, like a malformed URL or an invalid
+//! header value.
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::config::{Missing, Set};
+
+#[derive(Debug)]
+pub struct Request {
+    pub method: String,
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidationError {
+    InvalidUrl(String),
+    InvalidHeaderValue { name: String, value: String },
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationError::InvalidUrl(url) => write!(f, "`{url}` is not a valid URL"),
+            ValidationError::InvalidHeaderValue { name, value } => {
+                write!(f, "header `{name}` has an invalid value `{value}`")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+pub struct RequestBuilder<MethodState, UrlState> {
+    method: Option<String>,
+    url: Option<String>,
+    headers: Vec<(String, String)>,
+    _method_state: PhantomData<MethodState>,
+    _url_state: PhantomData<UrlState>,
+}
+
+impl RequestBuilder<Missing, Missing> {
+    pub fn new() -> Self {
+        RequestBuilder {
+            method: None,
+            url: None,
+            headers: Vec::new(),
+            _method_state: PhantomData,
+            _url_state: PhantomData,
+        }
+    }
+}
+
+impl Default for RequestBuilder<Missing, Missing> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<UrlState> RequestBuilder<Missing, UrlState> {
+    pub fn method(self, method: impl Into<String>) -> RequestBuilder<Set, UrlState> {
+        RequestBuilder {
+            method: Some(method.into()),
+            url: self.url,
+            headers: self.headers,
+            _method_state: PhantomData,
+            _url_state: PhantomData,
+        }
+    }
+}
+
+impl<MethodState> RequestBuilder<MethodState, Missing> {
+    pub fn url(self, url: impl Into<String>) -> RequestBuilder<MethodState, Set> {
+        RequestBuilder {
+            method: self.method,
+            url: Some(url.into()),
+            headers: self.headers,
+            _method_state: PhantomData,
+            _url_state: PhantomData,
+        }
+    }
+}
+
+impl<MethodState, UrlState> RequestBuilder<MethodState, UrlState> {
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+}
+
+impl RequestBuilder<Set, Set> {
+    /// Only callable once both `method` and `url` have been provided.
+    /// Still fallible: the URL format and header values are only known
+    /// to be well-formed at runtime.
+    pub fn build(self) -> Result<Request, ValidationError> {
+        let url = self.url.expect("Set state guarantees url is present");
+        if !is_valid_url(&url) {
+            return Err(ValidationError::InvalidUrl(url));
+        }
+
+        for (name, value) in &self.headers {
+            if !is_valid_header_value(value) {
+                return Err(ValidationError::InvalidHeaderValue { name: name.clone(), value: value.clone() });
+            }
+        }
+
+        Ok(Request {
+            method: self.method.expect("Set state guarantees method is present"),
+            url,
+            headers: self.headers,
+        })
+    }
+}
+
+fn is_valid_url(url: &str) -> bool {
+    let Some(rest) = url.strip_prefix("http://").or_else(|| url.strip_prefix("https://")) else {
+        return false;
+    };
+    !rest.is_empty() && !rest.starts_with('/')
+}
+
+/// Header values may not contain CR/LF, which would let a caller smuggle
+/// extra headers into the request.
+fn is_valid_header_value(value: &str) -> bool {
+    !value.contains(['\r', '\n'])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_a_valid_request() {
+        let request = RequestBuilder::new()
+            .method("GET")
+            .url("https://example.com/widgets")
+            .header("Accept", "application/json")
+            .build()
+            .unwrap();
+
+        assert_eq!(request.method, "GET");
+        assert_eq!(request.url, "https://example.com/widgets");
+        assert_eq!(request.headers, vec![("Accept".to_string(), "application/json".to_string())]);
+    }
+
+    #[test]
+    fn a_malformed_url_is_rejected_at_build_time() {
+        let result = RequestBuilder::new().method("GET").url("not-a-url").build();
+        assert_eq!(result.unwrap_err(), ValidationError::InvalidUrl("not-a-url".to_string()));
+    }
+
+    #[test]
+    fn a_header_value_with_embedded_newlines_is_rejected() {
+        let result = RequestBuilder::new()
+            .method("GET")
+            .url("https://example.com")
+            .header("X-Evil", "value\r\nX-Injected: true")
+            .build();
+
+        assert!(matches!(result, Err(ValidationError::InvalidHeaderValue { .. })));
+    }
+
+    #[test]
+    fn build_is_unavailable_without_a_method() {
+        let t = trybuild::TestCases::new();
+        t.compile_fail("tests/compile_fail/request_missing_method.rs");
+    }
+}
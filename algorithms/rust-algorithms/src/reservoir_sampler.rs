@@ -0,0 +1,89 @@
+//! A reservoir sampler for drawing a uniform random sample from a stream
+//! of unknown length, using Algorithm R.
+
+use crate::rng::Rng;
+
+pub struct ReservoirSampler<T, R: Rng> {
+    capacity: usize,
+    seen: usize,
+    reservoir: Vec<T>,
+    rng: R,
+}
+
+impl<T, R: Rng> ReservoirSampler<T, R> {
+    pub fn new(capacity: usize, rng: R) -> Self {
+        ReservoirSampler {
+            capacity,
+            seen: 0,
+            reservoir: Vec::with_capacity(capacity),
+            rng,
+        }
+    }
+
+    /// Feeds one more item from the stream, keeping the reservoir a
+    /// uniform sample of every item seen so far.
+    pub fn add(&mut self, item: T) {
+        self.seen += 1;
+        if self.reservoir.len() < self.capacity {
+            self.reservoir.push(item);
+            return;
+        }
+
+        let index = (self.rng.next_f64() * self.seen as f64) as usize;
+        if index < self.capacity {
+            self.reservoir[index] = item;
+        }
+    }
+
+    pub fn sample(&self) -> &[T] {
+        &self.reservoir
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::Lcg;
+
+    #[test]
+    fn the_reservoir_holds_exactly_k_items_once_full() {
+        let mut sampler = ReservoirSampler::new(10, Lcg::new(1));
+        for i in 0..10_000 {
+            sampler.add(i);
+        }
+        assert_eq!(sampler.sample().len(), 10);
+    }
+
+    #[test]
+    fn a_reservoir_larger_than_the_stream_keeps_every_item() {
+        let mut sampler = ReservoirSampler::new(100, Lcg::new(1));
+        for i in 0..5 {
+            sampler.add(i);
+        }
+        assert_eq!(sampler.sample(), &[0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn each_items_inclusion_frequency_approaches_k_over_n_across_seeds() {
+        let n = 200;
+        let k = 20;
+        let trials = 2_000;
+        let mut inclusions = vec![0u32; n];
+
+        for seed in 0..trials {
+            let mut sampler = ReservoirSampler::new(k, Lcg::new(seed + 1));
+            for i in 0..n {
+                sampler.add(i);
+            }
+            for &item in sampler.sample() {
+                inclusions[item] += 1;
+            }
+        }
+
+        let expected = trials as f64 * k as f64 / n as f64;
+        for count in inclusions {
+            let deviation = (count as f64 - expected).abs() / expected;
+            assert!(deviation < 0.35, "deviation was {deviation}");
+        }
+    }
+}
@@ -0,0 +1,93 @@
+//! A minimal dependency resolver built on [`crate::version`]: for each
+//! required package, pick the highest version available that satisfies
+//! every constraint on it. It does not consult [`crate::deps`] or follow
+//! dependencies between packages.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::version::{Version, VersionReq};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResolveError {
+    /// No available version of `package` satisfies every requirement on it.
+    Unsatisfiable(String),
+}
+
+impl fmt::Display for ResolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ResolveError::Unsatisfiable(package) => write!(f, "no version of `{package}` satisfies its requirements"),
+        }
+    }
+}
+
+impl std::error::Error for ResolveError {}
+
+/// Picks the highest version of each requested package that satisfies
+/// every requirement listed for it in `requirements`.
+pub fn resolve(
+    requirements: &[(String, VersionReq)],
+    available: &HashMap<String, Vec<Version>>,
+) -> Result<HashMap<String, Version>, ResolveError> {
+    let mut requirements_by_package: HashMap<&str, Vec<&VersionReq>> = HashMap::new();
+    for (package, req) in requirements {
+        requirements_by_package.entry(package.as_str()).or_default().push(req);
+    }
+
+    let mut selected = HashMap::new();
+    for (package, reqs) in requirements_by_package {
+        let candidates = available.get(package).cloned().unwrap_or_default();
+        let best = candidates
+            .into_iter()
+            .filter(|version| reqs.iter().all(|req| req.matches(version)))
+            .max()
+            .ok_or_else(|| ResolveError::Unsatisfiable(package.to_string()))?;
+
+        selected.insert(package.to_string(), best);
+    }
+
+    Ok(selected)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn v(s: &str) -> Version {
+        s.parse().unwrap()
+    }
+
+    fn req(s: &str) -> VersionReq {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn resolves_the_highest_version_satisfying_overlapping_constraints() {
+        let requirements = vec![
+            ("left-pad".to_string(), req("^1.0.0")),
+            ("left-pad".to_string(), req("^1.2.0")),
+        ];
+        let available = HashMap::from([(
+            "left-pad".to_string(),
+            vec![v("1.1.0"), v("1.2.0"), v("1.3.0"), v("2.0.0")],
+        )]);
+
+        let resolved = resolve(&requirements, &available).unwrap();
+        assert_eq!(resolved.get("left-pad"), Some(&v("1.3.0")));
+    }
+
+    #[test]
+    fn an_impossible_constraint_set_errors() {
+        let requirements = vec![
+            ("left-pad".to_string(), req("^1.0.0")),
+            ("left-pad".to_string(), req("^2.0.0")),
+        ];
+        let available = HashMap::from([("left-pad".to_string(), vec![v("1.5.0"), v("2.5.0")])]);
+
+        assert_eq!(
+            resolve(&requirements, &available),
+            Err(ResolveError::Unsatisfiable("left-pad".to_string()))
+        );
+    }
+}
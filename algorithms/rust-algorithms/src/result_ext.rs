@@ -0,0 +1,47 @@
+//! Helpers for processing a batch of `Result`s: either keep going and
+//! collect every success and failure, or short-circuit on the first error.
+
+/// Splits an iterator of `Result`s into its successes and failures,
+/// preserving relative order within each list.
+pub fn partition_results<T, E>(iter: impl IntoIterator<Item = Result<T, E>>) -> (Vec<T>, Vec<E>) {
+    let mut oks = Vec::new();
+    let mut errs = Vec::new();
+    for item in iter {
+        match item {
+            Ok(value) => oks.push(value),
+            Err(error) => errs.push(error),
+        }
+    }
+    (oks, errs)
+}
+
+/// Collects successes into a `Vec`, returning the first `Err` encountered
+/// and abandoning the rest.
+pub fn collect_or_first_error<T, E>(iter: impl IntoIterator<Item = Result<T, E>>) -> Result<Vec<T>, E> {
+    iter.into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn partitions_a_mix_of_ok_and_err() {
+        let input: Vec<Result<i32, &str>> = vec![Ok(1), Err("a"), Ok(2), Err("b"), Ok(3)];
+        let (oks, errs) = partition_results(input);
+        assert_eq!(oks, vec![1, 2, 3]);
+        assert_eq!(errs, vec!["a", "b"]);
+    }
+
+    #[test]
+    fn collect_or_first_error_short_circuits() {
+        let input: Vec<Result<i32, &str>> = vec![Ok(1), Ok(2), Err("boom"), Ok(3)];
+        assert_eq!(collect_or_first_error(input), Err("boom"));
+    }
+
+    #[test]
+    fn collect_or_first_error_succeeds_when_all_ok() {
+        let input: Vec<Result<i32, &str>> = vec![Ok(1), Ok(2), Ok(3)];
+        assert_eq!(collect_or_first_error(input), Ok(vec![1, 2, 3]));
+    }
+}
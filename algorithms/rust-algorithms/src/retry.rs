@@ -0,0 +1,107 @@
+//! A retry-with-backoff combinator for fallible operations, with the sleep
+//! step pulled out behind a trait so tests don't have to wait in real time.
+
+use std::time::Duration;
+
+/// Abstracts "wait this long" so tests can substitute a no-op sleeper.
+pub trait Sleeper {
+    fn sleep(&mut self, duration: Duration);
+}
+
+/// Sleeps for real using `std::thread::sleep`.
+#[derive(Default)]
+pub struct RealSleeper;
+
+impl Sleeper for RealSleeper {
+    fn sleep(&mut self, duration: Duration) {
+        std::thread::sleep(duration);
+    }
+}
+
+/// Retries `op` up to `max_attempts` times, waiting `backoff` between
+/// attempts (via `sleeper`). Returns the first `Ok`, or the last `Err` once
+/// attempts are exhausted.
+pub fn retry_with<T, E, F, S>(mut op: F, max_attempts: usize, backoff: Duration, sleeper: &mut S) -> Result<T, E>
+where
+    F: FnMut() -> Result<T, E>,
+    S: Sleeper,
+{
+    assert!(max_attempts > 0, "max_attempts must be at least 1");
+    let mut attempt = 0;
+    loop {
+        attempt += 1;
+        match op() {
+            Ok(value) => return Ok(value),
+            Err(error) => {
+                if attempt >= max_attempts {
+                    return Err(error);
+                }
+                sleeper.sleep(backoff);
+            }
+        }
+    }
+}
+
+/// Convenience wrapper over [`retry_with`] using a real, thread-sleeping
+/// backoff.
+pub fn retry<T, E, F>(op: F, max_attempts: usize, backoff: Duration) -> Result<T, E>
+where
+    F: FnMut() -> Result<T, E>,
+{
+    retry_with(op, max_attempts, backoff, &mut RealSleeper)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    #[derive(Default)]
+    struct NoopSleeper {
+        sleeps: RefCell<usize>,
+    }
+
+    impl Sleeper for NoopSleeper {
+        fn sleep(&mut self, _duration: Duration) {
+            *self.sleeps.borrow_mut() += 1;
+        }
+    }
+
+    #[test]
+    fn succeeds_on_third_attempt() {
+        let mut attempts = 0;
+        let mut sleeper = NoopSleeper::default();
+        let result = retry_with(
+            || {
+                attempts += 1;
+                if attempts < 3 {
+                    Err("not yet")
+                } else {
+                    Ok(attempts)
+                }
+            },
+            5,
+            Duration::from_millis(1),
+            &mut sleeper,
+        );
+        assert_eq!(result, Ok(3));
+        assert_eq!(attempts, 3);
+    }
+
+    #[test]
+    fn returns_last_error_after_exhausting_attempts() {
+        let mut attempts = 0;
+        let mut sleeper = NoopSleeper::default();
+        let result: Result<(), &str> = retry_with(
+            || {
+                attempts += 1;
+                Err("always fails")
+            },
+            4,
+            Duration::from_millis(1),
+            &mut sleeper,
+        );
+        assert_eq!(result, Err("always fails"));
+        assert_eq!(attempts, 4);
+    }
+}
@@ -0,0 +1,115 @@
+//! A fixed-capacity circular queue backed by `Vec<Option<T>>`.
+
+/// Returned by [`RingBuffer::push`] in non-overwriting mode when the
+/// buffer is already full.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BufferFull;
+
+pub struct RingBuffer<T> {
+    slots: Vec<Option<T>>,
+    head: usize,
+    len: usize,
+}
+
+impl<T> RingBuffer<T> {
+    pub fn with_capacity(capacity: usize) -> Self {
+        assert!(capacity > 0, "capacity must be at least 1");
+        let mut slots = Vec::with_capacity(capacity);
+        slots.resize_with(capacity, || None);
+        RingBuffer {
+            slots,
+            head: 0,
+            len: 0,
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.slots.len()
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn tail(&self) -> usize {
+        (self.head + self.len) % self.capacity()
+    }
+
+    /// Pushes `value`, overwriting the oldest element once the buffer is
+    /// full.
+    pub fn push(&mut self, value: T) {
+        let index = self.tail();
+        self.slots[index] = Some(value);
+        if self.len < self.capacity() {
+            self.len += 1;
+        } else {
+            // Buffer was already full: the slot we just overwrote was the
+            // oldest element, so the logical head moves forward one.
+            self.head = (self.head + 1) % self.capacity();
+        }
+    }
+
+    /// Pushes `value` without overwriting, failing with [`BufferFull`] if
+    /// the buffer has no room.
+    pub fn try_push(&mut self, value: T) -> Result<(), BufferFull> {
+        if self.len == self.capacity() {
+            return Err(BufferFull);
+        }
+        self.push(value);
+        Ok(())
+    }
+
+    /// Removes and returns the oldest element, or `None` if empty.
+    pub fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        let value = self.slots[self.head].take();
+        self.head = (self.head + 1) % self.capacity();
+        self.len -= 1;
+        value
+    }
+
+    /// Iterates from oldest to newest without consuming the buffer.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        (0..self.len).map(move |i| self.slots[(self.head + i) % self.capacity()].as_ref().unwrap())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overwrite_mode_drops_the_oldest_element() {
+        let mut buffer = RingBuffer::with_capacity(3);
+        buffer.push(1);
+        buffer.push(2);
+        buffer.push(3);
+        buffer.push(4); // overwrites 1
+
+        assert_eq!(buffer.iter().copied().collect::<Vec<_>>(), vec![2, 3, 4]);
+    }
+
+    #[test]
+    fn try_push_rejects_writes_once_full() {
+        let mut buffer = RingBuffer::with_capacity(2);
+        assert_eq!(buffer.try_push(1), Ok(()));
+        assert_eq!(buffer.try_push(2), Ok(()));
+        assert_eq!(buffer.try_push(3), Err(BufferFull));
+    }
+
+    #[test]
+    fn pop_returns_elements_in_insertion_order() {
+        let mut buffer = RingBuffer::with_capacity(3);
+        buffer.push(1);
+        buffer.push(2);
+        assert_eq!(buffer.pop(), Some(1));
+        assert_eq!(buffer.pop(), Some(2));
+        assert_eq!(buffer.pop(), None);
+    }
+}
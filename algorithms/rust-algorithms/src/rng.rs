@@ -0,0 +1,57 @@
+//! A minimal pseudo-random number generator behind an [`Rng`] trait, so
+//! sampling code can take its randomness as a parameter and tests can
+//! supply a fixed seed, mirroring how [`crate::rate_limiter::Clock`]
+//! makes time injectable.
+
+/// A source of pseudo-random numbers.
+pub trait Rng {
+    /// Returns the next pseudo-random `u64`.
+    fn next_u64(&mut self) -> u64;
+
+    /// Returns a pseudo-random `f64` in `[0, 1)`.
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+/// A linear-congruential generator. Not suitable for cryptographic use,
+/// but fast, seedable, and reproducible, which is what the samplers in
+/// this crate need.
+pub struct Lcg {
+    state: u64,
+}
+
+impl Lcg {
+    pub fn new(seed: u64) -> Self {
+        Lcg { state: seed }
+    }
+}
+
+impl Rng for Lcg {
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_mul(6364136223846793005).wrapping_add(1);
+        self.state
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_same_seed_produces_the_same_sequence() {
+        let mut a = Lcg::new(42);
+        let mut b = Lcg::new(42);
+        assert_eq!(a.next_u64(), b.next_u64());
+        assert_eq!(a.next_u64(), b.next_u64());
+    }
+
+    #[test]
+    fn next_f64_stays_within_the_unit_interval() {
+        let mut rng = Lcg::new(1);
+        for _ in 0..1000 {
+            let x = rng.next_f64();
+            assert!((0.0..1.0).contains(&x));
+        }
+    }
+}
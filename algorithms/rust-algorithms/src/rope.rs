@@ -0,0 +1,175 @@
+//! A rope for large-text editing: a binary tree of string chunks, so
+//! edits split and join nodes instead of shifting the whole document.
+//! [`Rope::new`] builds a balanced tree; `insert` and `concat` add nodes
+//! without rebalancing, so repeated edits can make the tree deeper than
+//! O(log n). Splits always land on `char` boundaries so multi-byte text
+//! is never cut mid-character.
+
+const LEAF_CAPACITY: usize = 1024;
+
+enum Node {
+    Leaf(String),
+    Concat {
+        left: Box<Node>,
+        right: Box<Node>,
+        /// The char length of `left`, so descents know which side holds
+        /// a given char index without visiting `left` itself.
+        weight: usize,
+        len: usize,
+    },
+}
+
+impl Node {
+    fn char_len(&self) -> usize {
+        match self {
+            Node::Leaf(s) => s.chars().count(),
+            Node::Concat { len, .. } => *len,
+        }
+    }
+
+    fn concat(left: Node, right: Node) -> Node {
+        let weight = left.char_len();
+        let len = weight + right.char_len();
+        Node::Concat { left: Box::new(left), right: Box::new(right), weight, len }
+    }
+
+    /// Splits this node into two at char index `at`, cutting leaves at
+    /// the equivalent byte offset.
+    fn split(self, at: usize) -> (Node, Node) {
+        match self {
+            Node::Leaf(s) => {
+                let byte_at = s.char_indices().nth(at).map_or(s.len(), |(i, _)| i);
+                let (left, right) = s.split_at(byte_at);
+                (Node::Leaf(left.to_string()), Node::Leaf(right.to_string()))
+            }
+            Node::Concat { left, right, weight, .. } => {
+                if at <= weight {
+                    let (left_left, left_right) = left.split(at);
+                    (left_left, Node::concat(left_right, *right))
+                } else {
+                    let (right_left, right_right) = right.split(at - weight);
+                    (Node::concat(*left, right_left), right_right)
+                }
+            }
+        }
+    }
+
+    fn push_slice(&self, start: usize, end: usize, out: &mut String) {
+        if start >= end {
+            return;
+        }
+        match self {
+            Node::Leaf(s) => {
+                let chars: Vec<char> = s.chars().collect();
+                out.extend(&chars[start..end]);
+            }
+            Node::Concat { left, right, weight, .. } => {
+                let weight = *weight;
+                if start < weight {
+                    left.push_slice(start, end.min(weight), out);
+                }
+                if end > weight {
+                    right.push_slice(start.saturating_sub(weight), end - weight, out);
+                }
+            }
+        }
+    }
+
+    /// Builds a balanced leaf chunk from `text`, recursively halving
+    /// until each leaf is at most [`LEAF_CAPACITY`] chars.
+    fn from_str(text: &str) -> Node {
+        let chars: Vec<char> = text.chars().collect();
+        if chars.len() <= LEAF_CAPACITY {
+            return Node::Leaf(text.to_string());
+        }
+
+        let mid = chars.len() / 2;
+        let byte_mid = text.char_indices().nth(mid).map_or(text.len(), |(i, _)| i);
+        let (left, right) = text.split_at(byte_mid);
+        Node::concat(Node::from_str(left), Node::from_str(right))
+    }
+}
+
+pub struct Rope {
+    root: Node,
+}
+
+impl Rope {
+    pub fn new(text: &str) -> Self {
+        Rope { root: Node::from_str(text) }
+    }
+
+    pub fn char_len(&self) -> usize {
+        self.root.char_len()
+    }
+
+    /// Inserts `text` at char index `char_index`, splitting and
+    /// re-concatenating the tree around it. The split follows one path
+    /// from the root, and the result is not rebalanced.
+    pub fn insert(&mut self, char_index: usize, text: &str) {
+        let root = std::mem::replace(&mut self.root, Node::Leaf(String::new()));
+        let (left, right) = root.split(char_index);
+        self.root = Node::concat(Node::concat(left, Node::from_str(text)), right);
+    }
+
+    /// Extracts the text in the half-open char range `[range.start,
+    /// range.end)`.
+    pub fn slice(&self, range: std::ops::Range<usize>) -> String {
+        let mut out = String::new();
+        self.root.push_slice(range.start, range.end.min(self.char_len()), &mut out);
+        out
+    }
+
+    pub fn concat(self, other: Rope) -> Rope {
+        Rope { root: Node::concat(self.root, other.root) }
+    }
+}
+
+impl std::fmt::Display for Rope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.slice(0..self.char_len()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slice_returns_the_expected_substring() {
+        let rope = Rope::new("hello world");
+        assert_eq!(rope.slice(6..11), "world");
+    }
+
+    #[test]
+    fn insert_in_the_middle_matches_the_equivalent_naive_string_edit() {
+        let mut rope = Rope::new("hello world");
+        let mut naive = String::from("hello world");
+
+        rope.insert(5, ", there");
+        naive.insert_str(5, ", there");
+
+        assert_eq!(rope.to_string(), naive);
+    }
+
+    #[test]
+    fn concat_joins_two_ropes() {
+        let rope = Rope::new("hello, ").concat(Rope::new("world"));
+        assert_eq!(rope.to_string(), "hello, world");
+    }
+
+    #[test]
+    fn a_large_document_insert_matches_the_naive_edit_and_preserves_char_boundaries() {
+        let base: String = "héllo wörld! ".chars().cycle().take(1_000_000).collect();
+        let mut rope = Rope::new(&base);
+        let mut naive = base.clone();
+
+        let midpoint = naive.chars().count() / 2;
+        rope.insert(midpoint, "—inserted—");
+        let byte_mid = naive.char_indices().nth(midpoint).map_or(naive.len(), |(i, _)| i);
+        naive.insert_str(byte_mid, "—inserted—");
+
+        assert_eq!(rope.char_len(), naive.chars().count());
+        assert_eq!(rope.to_string(), naive);
+    }
+}
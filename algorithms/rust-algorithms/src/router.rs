@@ -0,0 +1,139 @@
+//! A minimal HTTP-style router: path patterns with `:param` segments and
+//! a trailing `*` wildcard, stored in a trie so matching a path costs
+//! O(segments) rather than a linear scan of every registered pattern.
+
+use std::collections::HashMap;
+
+struct RouterNode<H> {
+    literal_children: HashMap<String, RouterNode<H>>,
+    param_child: Option<(String, Box<RouterNode<H>>)>,
+    wildcard_handler: Option<H>,
+    handler: Option<H>,
+}
+
+impl<H> Default for RouterNode<H> {
+    fn default() -> Self {
+        RouterNode {
+            literal_children: HashMap::new(),
+            param_child: None,
+            wildcard_handler: None,
+            handler: None,
+        }
+    }
+}
+
+pub struct Router<H> {
+    root: RouterNode<H>,
+}
+
+impl<H> Router<H> {
+    pub fn new() -> Self {
+        Router { root: RouterNode::default() }
+    }
+
+    /// Registers `handler` for `pattern`, e.g. `/users/:id` or
+    /// `/files/*`.
+    pub fn register(&mut self, pattern: &str, handler: H) {
+        let mut node = &mut self.root;
+        let segments: Vec<&str> = pattern.split('/').filter(|s| !s.is_empty()).collect();
+
+        for segment in &segments {
+            if *segment == "*" {
+                node.wildcard_handler = Some(handler);
+                return;
+            } else if let Some(name) = segment.strip_prefix(':') {
+                let (_, boxed) = node
+                    .param_child
+                    .get_or_insert_with(|| (name.to_string(), Box::new(RouterNode::default())));
+                node = boxed;
+            } else {
+                node = node.literal_children.entry(segment.to_string()).or_default();
+            }
+        }
+
+        node.handler = Some(handler);
+    }
+
+    /// Matches `path` against every registered pattern, returning the
+    /// handler and any captured `:param` values.
+    pub fn match_path(&self, path: &str) -> Option<(&H, HashMap<String, String>)> {
+        let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+        let mut params = HashMap::new();
+        let handler = match_node(&self.root, &segments, &mut params)?;
+        Some((handler, params))
+    }
+}
+
+impl<H> Default for Router<H> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn match_node<'a, H>(
+    node: &'a RouterNode<H>,
+    segments: &[&str],
+    params: &mut HashMap<String, String>,
+) -> Option<&'a H> {
+    let Some((first, rest)) = segments.split_first() else {
+        return node.handler.as_ref();
+    };
+
+    if let Some(child) = node.literal_children.get(*first) {
+        if let Some(handler) = match_node(child, rest, params) {
+            return Some(handler);
+        }
+    }
+
+    if let Some((name, child)) = &node.param_child {
+        params.insert(name.clone(), (*first).to_string());
+        if let Some(handler) = match_node(child, rest, params) {
+            return Some(handler);
+        }
+        params.remove(name);
+    }
+
+    node.wildcard_handler.as_ref()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_param_segment_captures_its_value() {
+        let mut router = Router::new();
+        router.register("/users/:id", "get_user");
+
+        let (handler, params) = router.match_path("/users/42").unwrap();
+        assert_eq!(*handler, "get_user");
+        assert_eq!(params.get("id"), Some(&"42".to_string()));
+    }
+
+    #[test]
+    fn a_shorter_path_does_not_match() {
+        let mut router = Router::new();
+        router.register("/users/:id", "get_user");
+
+        assert!(router.match_path("/users").is_none());
+    }
+
+    #[test]
+    fn a_wildcard_matches_the_remaining_path() {
+        let mut router = Router::new();
+        router.register("/files/*", "serve_file");
+
+        assert!(router.match_path("/files/a/b/c.txt").is_some());
+    }
+
+    #[test]
+    fn literal_segments_take_priority_over_params() {
+        let mut router = Router::new();
+        router.register("/users/:id", "get_user");
+        router.register("/users/me", "get_current_user");
+
+        let (handler, params) = router.match_path("/users/me").unwrap();
+        assert_eq!(*handler, "get_current_user");
+        assert!(params.is_empty());
+    }
+}
@@ -0,0 +1,71 @@
+//! A sealed trait: `Format` can be implemented only by types in this crate,
+//! because implementing it also requires implementing the private
+//! `private::Sealed` supertrait that outside crates can't name.
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// A serialization format. Sealed so the set of formats is closed to this
+/// crate — callers can still write code generic over `F: Format`.
+pub trait Format: private::Sealed {
+    fn serialize(&self, fields: &[(&str, &str)]) -> String;
+}
+
+pub struct Json;
+pub struct Csv;
+
+impl private::Sealed for Json {}
+impl private::Sealed for Csv {}
+
+impl Format for Json {
+    fn serialize(&self, fields: &[(&str, &str)]) -> String {
+        let body = fields
+            .iter()
+            .map(|(key, value)| format!("\"{key}\":\"{value}\""))
+            .collect::<Vec<_>>()
+            .join(",");
+        format!("{{{body}}}")
+    }
+}
+
+impl Format for Csv {
+    fn serialize(&self, fields: &[(&str, &str)]) -> String {
+        fields
+            .iter()
+            .map(|(_, value)| value.to_string())
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
+
+/// Dispatches to whichever [`Format`] the caller picked.
+pub fn serialize_with<F: Format>(format: &F, fields: &[(&str, &str)]) -> String {
+    format.serialize(fields)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_serializes_fields_as_an_object() {
+        let fields = [("name", "ada"), ("lang", "rust")];
+        assert_eq!(
+            serialize_with(&Json, &fields),
+            "{\"name\":\"ada\",\"lang\":\"rust\"}"
+        );
+    }
+
+    #[test]
+    fn csv_serializes_fields_as_values_only() {
+        let fields = [("name", "ada"), ("lang", "rust")];
+        assert_eq!(serialize_with(&Csv, &fields), "ada,rust");
+    }
+
+    #[test]
+    fn outside_crate_cannot_implement_format() {
+        let t = trybuild::TestCases::new();
+        t.compile_fail("tests/compile_fail/sealed_format.rs");
+    }
+}
@@ -0,0 +1,67 @@
+//! Binary search implemented from scratch, mirroring the contract of
+//! [`slice::binary_search_by`].
+
+use std::cmp::Ordering;
+
+/// Searches `slice` (assumed sorted with respect to `f`) for an element
+/// where `f` returns `Ordering::Equal`.
+///
+/// Returns `Ok(index)` of a matching element on a hit (if there are
+/// duplicates, the index of *some* match is returned, not necessarily the
+/// first or last), or `Err(insert_position)` — the index at which the
+/// element could be inserted to keep the slice sorted — on a miss.
+pub fn binary_search_by<T, F>(slice: &[T], mut f: F) -> Result<usize, usize>
+where
+    F: FnMut(&T) -> Ordering,
+{
+    let mut lo = 0usize;
+    let mut hi = slice.len();
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        match f(&slice[mid]) {
+            Ordering::Equal => return Ok(mid),
+            Ordering::Less => lo = mid + 1,
+            Ordering::Greater => hi = mid,
+        }
+    }
+    Err(lo)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_present_elements() {
+        let data = [1, 3, 5, 7, 9];
+        assert_eq!(binary_search_by(&data, |x| x.cmp(&7)), Ok(3));
+    }
+
+    #[test]
+    fn reports_insertion_point_on_miss() {
+        let data = [1, 3, 5, 7, 9];
+        assert_eq!(binary_search_by(&data, |x| x.cmp(&6)), Err(3));
+        assert_eq!(binary_search_by(&data, |x| x.cmp(&0)), Err(0));
+        assert_eq!(binary_search_by(&data, |x| x.cmp(&10)), Err(5));
+    }
+
+    #[test]
+    fn handles_empty_slice() {
+        let data: [i32; 0] = [];
+        assert_eq!(binary_search_by(&data, |x| x.cmp(&5)), Err(0));
+    }
+
+    #[test]
+    fn matches_std_on_random_sorted_data() {
+        let mut data: Vec<i32> = (0..500).map(|i| i * 2).collect();
+        data.sort_unstable();
+        for target in -5..1010 {
+            let ours = binary_search_by(&data, |x| x.cmp(&target));
+            let theirs = data.binary_search(&target);
+            assert_eq!(ours.is_ok(), theirs.is_ok());
+            if let (Err(a), Err(b)) = (ours, theirs) {
+                assert_eq!(a, b);
+            }
+        }
+    }
+}
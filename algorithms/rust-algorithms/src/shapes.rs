@@ -0,0 +1,89 @@
+//! Demonstrates the object-safe vs. non-object-safe split: `Shape` alone is
+//! object-safe and works as `Box<dyn Shape>`, but cloning trait objects
+//! needs the classic `clone_box` workaround since `Clone` itself isn't
+//! object-safe (it returns `Self`).
+
+/// The object-safe subset: no methods returning `Self` by value.
+pub trait Shape {
+    fn area(&self) -> f64;
+    fn draw(&self) -> String;
+}
+
+/// Extends `Shape` with a way to clone a boxed trait object, without
+/// requiring `Shape: Clone` (which would make `Shape` non-object-safe).
+pub trait ShapeClone: Shape {
+    fn clone_box(&self) -> Box<dyn ShapeClone>;
+}
+
+impl Clone for Box<dyn ShapeClone> {
+    fn clone(&self) -> Box<dyn ShapeClone> {
+        self.clone_box()
+    }
+}
+
+#[derive(Clone)]
+pub struct Circle {
+    pub radius: f64,
+}
+
+impl Shape for Circle {
+    fn area(&self) -> f64 {
+        std::f64::consts::PI * self.radius * self.radius
+    }
+
+    fn draw(&self) -> String {
+        format!("Circle(r={})", self.radius)
+    }
+}
+
+impl ShapeClone for Circle {
+    fn clone_box(&self) -> Box<dyn ShapeClone> {
+        Box::new(self.clone())
+    }
+}
+
+#[derive(Clone)]
+pub struct Rectangle {
+    pub width: f64,
+    pub height: f64,
+}
+
+impl Shape for Rectangle {
+    fn area(&self) -> f64 {
+        self.width * self.height
+    }
+
+    fn draw(&self) -> String {
+        format!("Rectangle({}x{})", self.width, self.height)
+    }
+}
+
+impl ShapeClone for Rectangle {
+    fn clone_box(&self) -> Box<dyn ShapeClone> {
+        Box::new(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cloned_boxed_shapes_preserve_total_area() {
+        let shapes: Vec<Box<dyn ShapeClone>> = vec![
+            Box::new(Circle { radius: 2.0 }),
+            Box::new(Rectangle {
+                width: 3.0,
+                height: 4.0,
+            }),
+        ];
+
+        let cloned = shapes.clone();
+
+        let total_area = |shapes: &[Box<dyn ShapeClone>]| -> f64 {
+            shapes.iter().map(|s| s.area()).sum()
+        };
+
+        assert_eq!(total_area(&shapes), total_area(&cloned));
+    }
+}
@@ -0,0 +1,145 @@
+//! An event counter bucketed into fixed time slots (e.g. 1s buckets over
+//! a 60s window), for observability metrics that need a windowed total
+//! or rate without keeping every timestamp.
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use crate::rate_limiter::{Clock, SystemClock};
+
+struct Bucket {
+    start: Instant,
+    count: u64,
+}
+
+/// Counts events into `bucket_width`-sized slots, rotating out slots
+/// older than `window` lazily whenever the counter is queried.
+pub struct SlidingCounter<C: Clock = SystemClock> {
+    bucket_width: Duration,
+    window: Duration,
+    clock: C,
+    buckets: VecDeque<Bucket>,
+}
+
+impl SlidingCounter<SystemClock> {
+    pub fn new(bucket_width: Duration, window: Duration) -> Self {
+        SlidingCounter::with_clock(bucket_width, window, SystemClock)
+    }
+}
+
+impl<C: Clock> SlidingCounter<C> {
+    pub fn with_clock(bucket_width: Duration, window: Duration, clock: C) -> Self {
+        SlidingCounter {
+            bucket_width,
+            window,
+            clock,
+            buckets: VecDeque::new(),
+        }
+    }
+
+    /// Records one event at the current time.
+    pub fn record(&mut self) {
+        let now = self.clock.now();
+        self.evict_stale(now);
+
+        match self.buckets.back_mut() {
+            Some(bucket) if now.duration_since(bucket.start) < self.bucket_width => {
+                bucket.count += 1;
+            }
+            _ => self.buckets.push_back(Bucket { start: now, count: 1 }),
+        }
+    }
+
+    /// The total events recorded within the last `window`.
+    pub fn total_in_window(&mut self) -> u64 {
+        let now = self.clock.now();
+        self.evict_stale(now);
+        self.buckets.iter().map(|bucket| bucket.count).sum()
+    }
+
+    /// The average events-per-second over `window`, based on the current
+    /// windowed total.
+    pub fn rate_per_second(&mut self) -> f64 {
+        self.total_in_window() as f64 / self.window.as_secs_f64()
+    }
+
+    fn evict_stale(&mut self, now: Instant) {
+        while let Some(bucket) = self.buckets.front() {
+            if now.duration_since(bucket.start) > self.window {
+                self.buckets.pop_front();
+            } else {
+                break;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    struct MockClock {
+        now: Cell<Instant>,
+    }
+
+    impl MockClock {
+        fn new() -> Self {
+            MockClock {
+                now: Cell::new(Instant::now()),
+            }
+        }
+
+        fn advance(&self, by: Duration) {
+            self.now.set(self.now.get() + by);
+        }
+    }
+
+    impl Clock for &MockClock {
+        fn now(&self) -> Instant {
+            self.now.get()
+        }
+    }
+
+    #[test]
+    fn total_in_window_counts_recent_events() {
+        let clock = MockClock::new();
+        let mut counter = SlidingCounter::with_clock(Duration::from_secs(1), Duration::from_secs(60), &clock);
+
+        for _ in 0..5 {
+            counter.record();
+        }
+
+        assert_eq!(counter.total_in_window(), 5);
+    }
+
+    #[test]
+    fn the_windowed_total_drops_as_old_buckets_expire() {
+        let clock = MockClock::new();
+        let mut counter = SlidingCounter::with_clock(Duration::from_secs(1), Duration::from_secs(10), &clock);
+
+        for _ in 0..3 {
+            counter.record();
+        }
+        clock.advance(Duration::from_secs(5));
+        for _ in 0..2 {
+            counter.record();
+        }
+        assert_eq!(counter.total_in_window(), 5);
+
+        clock.advance(Duration::from_secs(6));
+        assert_eq!(counter.total_in_window(), 2);
+    }
+
+    #[test]
+    fn rate_per_second_is_the_windowed_total_over_the_window() {
+        let clock = MockClock::new();
+        let mut counter = SlidingCounter::with_clock(Duration::from_secs(1), Duration::from_secs(10), &clock);
+
+        for _ in 0..20 {
+            counter.record();
+        }
+
+        assert_eq!(counter.rate_per_second(), 2.0);
+    }
+}
@@ -0,0 +1,56 @@
+//! The maximum of every size-`k` window of a slice, computed in O(n) via
+//! a monotonic deque of candidate indices instead of re-scanning each
+//! window.
+
+use std::collections::VecDeque;
+
+/// Returned when `k` doesn't describe a valid window size for the data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidWindow;
+
+/// The max of each contiguous window of length `k`, in order.
+pub fn sliding_window_max<T: Ord + Copy>(data: &[T], k: usize) -> Result<Vec<T>, InvalidWindow> {
+    if k == 0 || k > data.len() {
+        return Err(InvalidWindow);
+    }
+
+    let mut deque: VecDeque<usize> = VecDeque::new();
+    let mut maxes = Vec::with_capacity(data.len() - k + 1);
+
+    for (i, &value) in data.iter().enumerate() {
+        while deque.front().is_some_and(|&front| front + k <= i) {
+            deque.pop_front();
+        }
+        while deque.back().is_some_and(|&back| data[back] <= value) {
+            deque.pop_back();
+        }
+        deque.push_back(i);
+
+        if i + 1 >= k {
+            maxes.push(data[*deque.front().unwrap()]);
+        }
+    }
+
+    Ok(maxes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn computes_the_max_of_each_window() {
+        let data = [1, 3, -1, -3, 5, 3, 6, 7];
+        assert_eq!(sliding_window_max(&data, 3), Ok(vec![3, 3, 5, 5, 6, 7]));
+    }
+
+    #[test]
+    fn a_window_of_zero_is_rejected() {
+        assert_eq!(sliding_window_max(&[1, 2, 3], 0), Err(InvalidWindow));
+    }
+
+    #[test]
+    fn a_window_larger_than_the_data_is_rejected() {
+        assert_eq!(sliding_window_max(&[1, 2], 3), Err(InvalidWindow));
+    }
+}
@@ -0,0 +1,147 @@
+//! In-place quicksort and stable mergesort, both driven by a caller-supplied
+//! comparator.
+
+use std::cmp::Ordering;
+
+/// Sorts `slice` in place using quicksort with a median-of-three pivot
+/// choice, which keeps already-sorted (and reverse-sorted) inputs from
+/// hitting the worst case.
+pub fn quicksort_by<T, F>(slice: &mut [T], cmp: &F)
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    if slice.len() <= 1 {
+        return;
+    }
+    let pivot = partition(slice, cmp);
+    let (left, right) = slice.split_at_mut(pivot);
+    quicksort_by(left, cmp);
+    quicksort_by(&mut right[1..], cmp);
+}
+
+/// Partitions around a median-of-three pivot (first, middle, last) and
+/// returns its final index.
+fn partition<T, F>(slice: &mut [T], cmp: &F) -> usize
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    let len = slice.len();
+    let mid = len / 2;
+    let pivot_index = median_of_three(slice, 0, mid, len - 1, cmp);
+    slice.swap(pivot_index, len - 1);
+
+    let mut store = 0;
+    for i in 0..len - 1 {
+        if cmp(&slice[i], &slice[len - 1]) == Ordering::Less {
+            slice.swap(i, store);
+            store += 1;
+        }
+    }
+    slice.swap(store, len - 1);
+    store
+}
+
+fn median_of_three<T, F>(slice: &[T], a: usize, b: usize, c: usize, cmp: &F) -> usize
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    let (x, y, z) = (&slice[a], &slice[b], &slice[c]);
+    if cmp(x, y) == Ordering::Less {
+        if cmp(y, z) == Ordering::Less {
+            b
+        } else if cmp(x, z) == Ordering::Less {
+            c
+        } else {
+            a
+        }
+    } else if cmp(x, z) == Ordering::Less {
+        a
+    } else if cmp(y, z) == Ordering::Less {
+        c
+    } else {
+        b
+    }
+}
+
+/// Stable mergesort returning a freshly allocated, sorted `Vec`.
+pub fn mergesort<T, F>(slice: &[T], cmp: &F) -> Vec<T>
+where
+    T: Clone,
+    F: Fn(&T, &T) -> Ordering,
+{
+    if slice.len() <= 1 {
+        return slice.to_vec();
+    }
+    let mid = slice.len() / 2;
+    let left = mergesort(&slice[..mid], cmp);
+    let right = mergesort(&slice[mid..], cmp);
+    merge(&left, &right, cmp)
+}
+
+fn merge<T, F>(left: &[T], right: &[T], cmp: &F) -> Vec<T>
+where
+    T: Clone,
+    F: Fn(&T, &T) -> Ordering,
+{
+    let mut out = Vec::with_capacity(left.len() + right.len());
+    let (mut i, mut j) = (0, 0);
+    while i < left.len() && j < right.len() {
+        if cmp(&right[j], &left[i]) == Ordering::Less {
+            out.push(right[j].clone());
+            j += 1;
+        } else {
+            out.push(left[i].clone());
+            i += 1;
+        }
+    }
+    out.extend_from_slice(&left[i..]);
+    out.extend_from_slice(&right[j..]);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lcg(seed: &mut u64) -> u64 {
+        *seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1);
+        *seed
+    }
+
+    #[test]
+    fn quicksort_matches_std_sort_on_random_data() {
+        let mut seed = 42u64;
+        let mut data: Vec<i32> = (0..300).map(|_| (lcg(&mut seed) % 1000) as i32).collect();
+        let mut expected = data.clone();
+        expected.sort();
+        quicksort_by(&mut data, &i32::cmp);
+        assert_eq!(data, expected);
+    }
+
+    #[test]
+    fn quicksort_handles_already_sorted_input() {
+        let mut data: Vec<i32> = (0..200).collect();
+        let expected = data.clone();
+        quicksort_by(&mut data, &i32::cmp);
+        assert_eq!(data, expected);
+    }
+
+    #[test]
+    fn mergesort_matches_std_sort_on_random_data() {
+        let mut seed = 7u64;
+        let data: Vec<i32> = (0..300).map(|_| (lcg(&mut seed) % 1000) as i32).collect();
+        let mut expected = data.clone();
+        expected.sort();
+        assert_eq!(mergesort(&data, &i32::cmp), expected);
+    }
+
+    #[test]
+    fn mergesort_is_stable() {
+        let data: Vec<(i32, usize)> = vec![(1, 0), (2, 1), (1, 2), (2, 3), (1, 4)];
+        let sorted = mergesort(&data, &|a: &(i32, usize), b: &(i32, usize)| a.0.cmp(&b.0));
+        let ones: Vec<usize> = sorted.iter().filter(|(k, _)| *k == 1).map(|(_, i)| *i).collect();
+        let twos: Vec<usize> = sorted.iter().filter(|(k, _)| *k == 2).map(|(_, i)| *i).collect();
+        assert_eq!(ones, vec![0, 2, 4]);
+        assert_eq!(twos, vec![1, 3]);
+    }
+}
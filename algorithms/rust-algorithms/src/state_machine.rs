@@ -0,0 +1,118 @@
+//! A generic finite state machine driven by a caller-supplied transition
+//! table, rejecting any `(state, event)` pair it doesn't recognize.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransitionError<S, E> {
+    pub state: S,
+    pub event: E,
+}
+
+impl<S: fmt::Debug, E: fmt::Debug> fmt::Display for TransitionError<S, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "no transition from {:?} on event {:?}",
+            self.state, self.event
+        )
+    }
+}
+
+impl<S: fmt::Debug, E: fmt::Debug> std::error::Error for TransitionError<S, E> {}
+
+/// A state machine whose transitions are looked up in a fixed table built
+/// at construction time.
+pub struct StateMachine<S, E> {
+    state: S,
+    table: HashMap<(S, E), S>,
+}
+
+impl<S, E> StateMachine<S, E>
+where
+    S: Eq + Hash + Clone,
+    E: Eq + Hash + Clone,
+{
+    pub fn new(initial: S) -> Self {
+        StateMachine {
+            state: initial,
+            table: HashMap::new(),
+        }
+    }
+
+    /// Registers a `(from, event) -> to` transition.
+    pub fn add_transition(&mut self, from: S, event: E, to: S) {
+        self.table.insert((from, event), to);
+    }
+
+    pub fn state(&self) -> &S {
+        &self.state
+    }
+
+    /// Applies `event` to the current state, moving to the transition
+    /// table's target state or erroring if none is registered.
+    pub fn on_event(&mut self, event: E) -> Result<&S, TransitionError<S, E>> {
+        match self.table.get(&(self.state.clone(), event.clone())) {
+            Some(next) => {
+                self.state = next.clone();
+                Ok(&self.state)
+            }
+            None => Err(TransitionError {
+                state: self.state.clone(),
+                event,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    enum Light {
+        Red,
+        Green,
+        Yellow,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    enum Event {
+        Green,
+        Yellow,
+        Red,
+    }
+
+    fn traffic_light() -> StateMachine<Light, Event> {
+        let mut machine = StateMachine::new(Light::Red);
+        machine.add_transition(Light::Red, Event::Green, Light::Green);
+        machine.add_transition(Light::Green, Event::Yellow, Light::Yellow);
+        machine.add_transition(Light::Yellow, Event::Red, Light::Red);
+        machine
+    }
+
+    #[test]
+    fn drives_red_green_yellow_red_through_the_expected_events() {
+        let mut machine = traffic_light();
+
+        assert_eq!(machine.on_event(Event::Green), Ok(&Light::Green));
+        assert_eq!(machine.on_event(Event::Yellow), Ok(&Light::Yellow));
+        assert_eq!(machine.on_event(Event::Red), Ok(&Light::Red));
+    }
+
+    #[test]
+    fn rejects_an_illegal_red_to_yellow_transition() {
+        let mut machine = traffic_light();
+
+        let err = machine.on_event(Event::Yellow).unwrap_err();
+        assert_eq!(
+            err,
+            TransitionError {
+                state: Light::Red,
+                event: Event::Yellow,
+            }
+        );
+    }
+}
@@ -0,0 +1,260 @@
+//! Tokenizes the arithmetic language from [`crate::expr`] over any
+//! [`Read`], for inputs too large to load as one `&str`. Chunks are read
+//! into a growing buffer; a token spanning a chunk boundary (e.g. a
+//! number cut off mid-digit) is simply left in the buffer until more
+//! bytes arrive, then re-scanned from its start.
+
+use std::fmt;
+use std::io::Read;
+
+use crate::expr::{Span, Token};
+
+const CHUNK_SIZE: usize = 4096;
+
+/// An error produced while streaming tokens, with the byte position (in
+/// the overall stream) where it occurred.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LexError {
+    pub message: String,
+    pub position: usize,
+}
+
+impl fmt::Display for LexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (at byte {})", self.message, self.position)
+    }
+}
+
+impl std::error::Error for LexError {}
+
+/// Tokenizes `reader` lazily, one token at a time.
+pub fn tokenize_reader<R: Read>(reader: R) -> impl Iterator<Item = Result<(Token, Span), LexError>> {
+    ReaderTokens {
+        reader,
+        buffer: Vec::new(),
+        consumed: 0,
+        eof: false,
+        done: false,
+    }
+}
+
+struct ReaderTokens<R> {
+    reader: R,
+    buffer: Vec<u8>,
+    /// Number of bytes already tokenized and dropped from `buffer`, i.e.
+    /// the absolute stream offset of `buffer[0]`.
+    consumed: usize,
+    /// Whether the underlying reader has reported EOF.
+    eof: bool,
+    /// Whether iteration has finished (an `Eof` token was already
+    /// produced, or an error occurred).
+    done: bool,
+}
+
+impl<R: Read> Iterator for ReaderTokens<R> {
+    type Item = Result<(Token, Span), LexError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        loop {
+            match self.try_take_token() {
+                TakeResult::Token(token, span) => {
+                    if token == Token::Eof {
+                        self.done = true;
+                    }
+                    return Some(Ok((token, span)));
+                }
+                TakeResult::Err(err) => {
+                    self.done = true;
+                    return Some(Err(err));
+                }
+                TakeResult::NeedMoreInput if self.eof => {
+                    // The reader is exhausted and only whitespace was left
+                    // in the buffer (at EOF `take_run` finishes a partial
+                    // run instead of asking for more), so the stream ends.
+                    self.done = true;
+                    return Some(Ok((Token::Eof, (self.consumed, self.consumed))));
+                }
+                TakeResult::NeedMoreInput => {
+                    if !self.fill_buffer() {
+                        self.eof = true;
+                    }
+                }
+            }
+        }
+    }
+}
+
+enum TakeResult {
+    Token(Token, Span),
+    Err(LexError),
+    NeedMoreInput,
+}
+
+impl<R: Read> ReaderTokens<R> {
+    /// Reads one more chunk into `buffer`. Returns `false` at EOF.
+    fn fill_buffer(&mut self) -> bool {
+        let mut chunk = vec![0u8; CHUNK_SIZE];
+        match self.reader.read(&mut chunk) {
+            Ok(0) => false,
+            Ok(n) => {
+                self.buffer.extend_from_slice(&chunk[..n]);
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// Attempts to lex the next token from the front of `buffer`. Returns
+    /// `NeedMoreInput` if the buffered bytes could be the start of a
+    /// longer token (a number or identifier run reaching the end of the
+    /// buffer) and the reader isn't known to be at EOF yet.
+    fn try_take_token(&mut self) -> TakeResult {
+        let mut i = 0;
+        while i < self.buffer.len() && matches!(self.buffer[i], b' ' | b'\t' | b'\n' | b'\r') {
+            i += 1;
+        }
+        self.drop_prefix(i);
+
+        if self.buffer.is_empty() {
+            return TakeResult::NeedMoreInput;
+        }
+
+        let c = self.buffer[0] as char;
+        match c {
+            '+' => self.take_fixed(Token::Plus, 1),
+            '-' => self.take_fixed(Token::Minus, 1),
+            '*' => self.take_fixed(Token::Star, 1),
+            '/' => self.take_fixed(Token::Slash, 1),
+            '(' => self.take_fixed(Token::LParen, 1),
+            ')' => self.take_fixed(Token::RParen, 1),
+            c if c.is_ascii_digit() || c == '.' => self.take_run(
+                |c| c.is_ascii_digit() || c == '.',
+                |text, span| {
+                    text.parse::<f64>()
+                        .map(Token::Number)
+                        .map_err(|_| LexError {
+                            message: format!("invalid number literal `{text}`"),
+                            position: span.0,
+                        })
+                },
+            ),
+            c if c.is_ascii_alphabetic() || c == '_' => self.take_run(
+                |c| c.is_ascii_alphanumeric() || c == '_',
+                |text, _| Ok(Token::Ident(text.to_string())),
+            ),
+            other => {
+                self.drop_prefix(1);
+                TakeResult::Err(LexError {
+                    message: format!("unexpected character `{other}`"),
+                    position: self.consumed - 1,
+                })
+            }
+        }
+    }
+
+    fn take_fixed(&mut self, token: Token, len: usize) -> TakeResult {
+        let start = self.consumed;
+        self.drop_prefix(len);
+        TakeResult::Token(token, (start, start + len))
+    }
+
+    /// Consumes a run of bytes matching `is_member`, extending past the
+    /// current buffer if the run reaches the buffer's end and the reader
+    /// might still have more of it.
+    fn take_run(
+        &mut self,
+        is_member: impl Fn(char) -> bool,
+        finish: impl FnOnce(&str, Span) -> Result<Token, LexError>,
+    ) -> TakeResult {
+        let mut end = 0;
+        while end < self.buffer.len() && is_member(self.buffer[end] as char) {
+            end += 1;
+        }
+        if end == self.buffer.len() && !self.eof {
+            return TakeResult::NeedMoreInput;
+        }
+
+        let start = self.consumed;
+        let text = String::from_utf8_lossy(&self.buffer[..end]).into_owned();
+        let span = (start, start + end);
+        self.drop_prefix(end);
+        match finish(&text, span) {
+            Ok(token) => TakeResult::Token(token, span),
+            Err(err) => TakeResult::Err(err),
+        }
+    }
+
+    fn drop_prefix(&mut self, n: usize) {
+        self.buffer.drain(..n);
+        self.consumed += n;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr;
+    use std::io::Cursor;
+
+    fn collect_streamed(source: &str, chunked_at: &[usize]) -> Vec<Token> {
+        // Feed the source through a reader that only ever returns the
+        // requested chunk sizes, so a token can straddle a boundary.
+        struct Chopped<'a> {
+            remaining: &'a [u8],
+            sizes: std::iter::Copied<std::slice::Iter<'a, usize>>,
+        }
+        impl<'a> Read for Chopped<'a> {
+            fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+                if self.remaining.is_empty() {
+                    return Ok(0);
+                }
+                let want = self.sizes.next().unwrap_or(self.remaining.len());
+                let n = want.min(self.remaining.len()).min(buf.len());
+                buf[..n].copy_from_slice(&self.remaining[..n]);
+                self.remaining = &self.remaining[n..];
+                Ok(n)
+            }
+        }
+
+        let reader = Chopped {
+            remaining: source.as_bytes(),
+            sizes: chunked_at.iter().copied(),
+        };
+        tokenize_reader(reader)
+            .map(|result| result.expect("no lex errors in this fixture").0)
+            .collect()
+    }
+
+    #[test]
+    fn streamed_tokens_match_the_in_memory_lexer_across_awkward_chunk_boundaries() {
+        let source = "12 + foo_bar * (3.5 - baz)";
+        let expected: Vec<Token> = expr::tokenize(source)
+            .expect("valid source")
+            .into_iter()
+            .map(|(token, _)| token)
+            .collect();
+
+        // Split mid-number, mid-identifier, and one byte at a time near
+        // the end, to exercise every partial-token path.
+        let streamed = collect_streamed(source, &[1, 1, 3, 4, 1, 1, 1, 1, 1000]);
+        assert_eq!(streamed, expected);
+
+        let streamed_one_byte_at_a_time = collect_streamed(source, &[1]);
+        assert_eq!(streamed_one_byte_at_a_time, expected);
+    }
+
+    #[test]
+    fn streamed_lexer_reports_an_error_with_position() {
+        let reader = Cursor::new(b"1 + @".to_vec());
+        let results: Vec<_> = tokenize_reader(reader).collect();
+        let error = results
+            .into_iter()
+            .find_map(|r| r.err())
+            .expect("stream contains an unexpected character");
+        assert_eq!(error.position, 4);
+    }
+}
@@ -0,0 +1,41 @@
+//! UTF-8-aware string helpers. Byte-oriented slicing (`&s[..n]`) can panic
+//! or produce garbage on multi-byte input, so anything that needs to count
+//! or cut a string by character belongs here instead.
+
+/// Counts the `char`s in `s`, which for multi-byte text differs from
+/// `s.len()` (a byte count).
+pub fn char_count(s: &str) -> usize {
+    s.chars().count()
+}
+
+/// Returns the longest prefix of `s` containing at most `n` `char`s,
+/// never splitting a multi-byte character.
+pub fn truncate_chars(s: &str, n: usize) -> &str {
+    match s.char_indices().nth(n) {
+        Some((byte_idx, _)) => &s[..byte_idx],
+        None => s,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn char_count_counts_characters_not_bytes() {
+        assert_eq!(char_count("crab"), 4);
+        assert_eq!(char_count("🦀"), 1);
+    }
+
+    #[test]
+    fn truncate_chars_never_splits_a_multi_byte_character() {
+        let s = "a🦀bc";
+        assert_eq!(truncate_chars(s, 2), "a🦀");
+        assert_eq!(char_count(truncate_chars(s, 2)), 2);
+    }
+
+    #[test]
+    fn truncate_chars_returns_the_whole_string_when_n_exceeds_its_length() {
+        assert_eq!(truncate_chars("hi", 10), "hi");
+    }
+}
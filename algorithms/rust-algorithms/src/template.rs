@@ -0,0 +1,97 @@
+//! `${KEY}`-style template rendering for config values, with `$$` as an
+//! escape for a literal `$`.
+
+use std::collections::HashMap;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TemplateError {
+    UndefinedVariable(String),
+    UnclosedPlaceholder,
+}
+
+impl fmt::Display for TemplateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TemplateError::UndefinedVariable(name) => write!(f, "undefined variable: {name}"),
+            TemplateError::UnclosedPlaceholder => write!(f, "unclosed '${{' placeholder"),
+        }
+    }
+}
+
+impl std::error::Error for TemplateError {}
+
+/// Replaces every `${KEY}` in `template` with `vars[KEY]`, and `$$` with
+/// a literal `$`.
+pub fn render_template(template: &str, vars: &HashMap<String, String>) -> Result<String, TemplateError> {
+    let mut out = String::with_capacity(template.len());
+    let mut chars = template.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '$' {
+            out.push(c);
+            continue;
+        }
+
+        match chars.peek() {
+            Some('$') => {
+                chars.next();
+                out.push('$');
+            }
+            Some('{') => {
+                chars.next();
+                let mut key = String::new();
+                let mut closed = false;
+                for next in chars.by_ref() {
+                    if next == '}' {
+                        closed = true;
+                        break;
+                    }
+                    key.push(next);
+                }
+                if !closed {
+                    return Err(TemplateError::UnclosedPlaceholder);
+                }
+                match vars.get(&key) {
+                    Some(value) => out.push_str(value),
+                    None => return Err(TemplateError::UndefinedVariable(key)),
+                }
+            }
+            _ => out.push('$'),
+        }
+    }
+
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn substitutes_every_placeholder() {
+        let vars = HashMap::from([
+            ("HOST".to_string(), "example.com".to_string()),
+            ("PORT".to_string(), "443".to_string()),
+        ]);
+
+        assert_eq!(render_template("${HOST}:${PORT}", &vars).unwrap(), "example.com:443");
+    }
+
+    #[test]
+    fn a_double_dollar_becomes_a_literal_dollar() {
+        assert_eq!(render_template("$$5", &HashMap::new()).unwrap(), "$5");
+    }
+
+    #[test]
+    fn an_undefined_variable_errors() {
+        let err = render_template("${MISSING}", &HashMap::new()).unwrap_err();
+        assert_eq!(err, TemplateError::UndefinedVariable("MISSING".to_string()));
+    }
+
+    #[test]
+    fn an_unclosed_placeholder_errors() {
+        let err = render_template("${HOST", &HashMap::new()).unwrap_err();
+        assert_eq!(err, TemplateError::UnclosedPlaceholder);
+    }
+}
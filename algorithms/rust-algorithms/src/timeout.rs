@@ -0,0 +1,57 @@
+//! Runs a blocking operation on a worker thread and gives up on it after a
+//! deadline, using a channel so the caller never has to poll.
+
+use std::fmt;
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeoutError;
+
+impl fmt::Display for TimeoutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "operation timed out")
+    }
+}
+
+impl std::error::Error for TimeoutError {}
+
+/// Runs `op` on a new thread, returning its result if it completes within
+/// `timeout` or [`TimeoutError`] otherwise. A timed-out `op` is left to
+/// finish on its worker thread; its result is simply dropped.
+pub fn run_with_timeout<T: Send + 'static>(
+    op: impl FnOnce() -> T + Send + 'static,
+    timeout: Duration,
+) -> Result<T, TimeoutError> {
+    let (sender, receiver) = mpsc::channel();
+
+    thread::spawn(move || {
+        let _ = sender.send(op());
+    });
+
+    receiver.recv_timeout(timeout).map_err(|_| TimeoutError)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_fast_operation_returns_ok() {
+        let result = run_with_timeout(|| 1 + 1, Duration::from_millis(200));
+        assert_eq!(result, Ok(2));
+    }
+
+    #[test]
+    fn a_slow_operation_times_out() {
+        let result = run_with_timeout(
+            || {
+                thread::sleep(Duration::from_millis(200));
+                42
+            },
+            Duration::from_millis(20),
+        );
+        assert_eq!(result, Err(TimeoutError));
+    }
+}
@@ -0,0 +1,61 @@
+//! Selecting the largest `k` elements of a possibly huge input while
+//! keeping memory at O(k), for "top N" queries.
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// Returns the `k` largest items of `iter`, in descending order, using a
+/// bounded min-heap so at most `k` items are held at once.
+pub fn top_k<T: Ord + Clone, I: IntoIterator<Item = T>>(iter: I, k: usize) -> Vec<T> {
+    if k == 0 {
+        return Vec::new();
+    }
+
+    let mut heap: BinaryHeap<Reverse<T>> = BinaryHeap::with_capacity(k);
+    for item in iter {
+        if heap.len() < k {
+            heap.push(Reverse(item));
+        } else if let Some(Reverse(smallest)) = heap.peek() {
+            if item > *smallest {
+                heap.pop();
+                heap.push(Reverse(item));
+            }
+        }
+    }
+
+    let mut result: Vec<T> = heap.into_iter().map(|Reverse(value)| value).collect();
+    result.sort_by(|a, b| b.cmp(a));
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lcg(seed: &mut u64) -> u64 {
+        *seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1);
+        *seed
+    }
+
+    #[test]
+    fn selects_the_top_three_of_a_shuffled_range() {
+        let mut data: Vec<i32> = (0..1000).collect();
+        let mut seed = 99u64;
+        for i in (1..data.len()).rev() {
+            let j = (lcg(&mut seed) % (i as u64 + 1)) as usize;
+            data.swap(i, j);
+        }
+
+        assert_eq!(top_k(data, 3), vec![999, 998, 997]);
+    }
+
+    #[test]
+    fn a_k_of_zero_returns_nothing() {
+        assert!(top_k([1, 2, 3], 0).is_empty());
+    }
+
+    #[test]
+    fn a_k_larger_than_the_input_returns_everything() {
+        assert_eq!(top_k([3, 1, 2], 10), vec![3, 2, 1]);
+    }
+}
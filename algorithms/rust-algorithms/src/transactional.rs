@@ -0,0 +1,82 @@
+//! A `Vec` wrapper for editor-style undoable edits: `begin` snapshots
+//! the current contents, mutations proceed in place, and `commit`/
+//! `rollback` either discard or restore that snapshot. Nested
+//! transactions stack, so an inner `rollback` only undoes edits made
+//! since its own `begin`.
+
+pub struct Transactional<T: Clone> {
+    items: Vec<T>,
+    snapshots: Vec<Vec<T>>,
+}
+
+impl<T: Clone> Transactional<T> {
+    pub fn new(items: Vec<T>) -> Self {
+        Transactional { items, snapshots: Vec::new() }
+    }
+
+    /// Snapshots the current contents so they can later be restored.
+    pub fn begin(&mut self) {
+        self.snapshots.push(self.items.clone());
+    }
+
+    /// Discards the most recent snapshot, keeping the current contents.
+    pub fn commit(&mut self) {
+        self.snapshots.pop();
+    }
+
+    /// Restores the contents to their state at the most recent `begin`.
+    pub fn rollback(&mut self) {
+        if let Some(snapshot) = self.snapshots.pop() {
+            self.items = snapshot;
+        }
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        &self.items
+    }
+
+    pub fn push(&mut self, item: T) {
+        self.items.push(item);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rollback_restores_the_snapshot_taken_at_begin() {
+        let mut transaction = Transactional::new(vec![1, 2, 3]);
+
+        transaction.begin();
+        transaction.push(4);
+        transaction.rollback();
+
+        assert_eq!(transaction.as_slice(), &[1, 2, 3]);
+    }
+
+    #[test]
+    fn commit_keeps_the_mutation() {
+        let mut transaction = Transactional::new(vec![1, 2, 3]);
+
+        transaction.begin();
+        transaction.push(4);
+        transaction.commit();
+
+        assert_eq!(transaction.as_slice(), &[1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn nested_transactions_stack() {
+        let mut transaction = Transactional::new(vec![1]);
+
+        transaction.begin();
+        transaction.push(2);
+        transaction.begin();
+        transaction.push(3);
+        transaction.rollback(); // undoes only the push of 3
+        transaction.commit(); // keeps the push of 2
+
+        assert_eq!(transaction.as_slice(), &[1, 2]);
+    }
+}
@@ -0,0 +1,61 @@
+//! A minimal Box-based binary tree, used as a simple structure to
+//! demonstrate [`PrettyPrint`] alongside the [`crate::expr::Expr`] AST.
+
+use std::fmt::Display;
+
+use crate::pretty::{pad, PrettyPrint};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TreeNode<T> {
+    pub value: T,
+    pub left: Option<Box<TreeNode<T>>>,
+    pub right: Option<Box<TreeNode<T>>>,
+}
+
+impl<T> TreeNode<T> {
+    pub fn leaf(value: T) -> Self {
+        TreeNode {
+            value,
+            left: None,
+            right: None,
+        }
+    }
+
+    pub fn with_children(value: T, left: Option<TreeNode<T>>, right: Option<TreeNode<T>>) -> Self {
+        TreeNode {
+            value,
+            left: left.map(Box::new),
+            right: right.map(Box::new),
+        }
+    }
+}
+
+impl<T: Display> PrettyPrint for TreeNode<T> {
+    fn pretty(&self, indent: usize) -> String {
+        let mut lines = vec![format!("{}{}", pad(indent), self.value)];
+        if let Some(left) = &self.left {
+            lines.push(left.pretty(indent + 1));
+        }
+        if let Some(right) = &self.right {
+            lines.push(right.pretty(indent + 1));
+        }
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pretty_prints_a_small_tree_with_indented_children() {
+        let tree = TreeNode::with_children(1, Some(TreeNode::leaf(2)), Some(TreeNode::leaf(3)));
+        assert_eq!(tree.pretty(0), "1\n  2\n  3");
+    }
+
+    #[test]
+    fn pretty_prints_a_lone_leaf() {
+        let leaf = TreeNode::leaf("root");
+        assert_eq!(leaf.pretty(0), "root");
+    }
+}
@@ -0,0 +1,54 @@
+//! A `tree_format!` macro building a [`crate::tree::TreeNode`] literal from nested
+//! bracket syntax, so tests and examples don't have to spell out
+//! `TreeNode::with_children` calls by hand.
+//!
+//! ```
+//! use rust_algorithms::tree_format;
+//! let tree = tree_format!(10, (5, (3), (7)), (20));
+//! assert_eq!(tree.value, 10);
+//! ```
+
+/// Expands `tree_format!(value)` to a leaf, `tree_format!(value, (left))`
+/// to a node with only a left child, and `tree_format!(value, (left),
+/// (right))` to a node with both — recursing into each parenthesized
+/// group. Anything else (a bare third argument, mismatched parens) fails
+/// to match any rule and is a compile error.
+#[macro_export]
+macro_rules! tree_format {
+    ($value:expr) => {
+        $crate::tree::TreeNode::leaf($value)
+    };
+    ($value:expr, ($($left:tt)*)) => {
+        $crate::tree::TreeNode::with_children($value, Some($crate::tree_format!($($left)*)), None)
+    };
+    ($value:expr, ($($left:tt)*), ($($right:tt)*)) => {
+        $crate::tree::TreeNode::with_children(
+            $value,
+            Some($crate::tree_format!($($left)*)),
+            Some($crate::tree_format!($($right)*)),
+        )
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tree::TreeNode;
+
+    #[test]
+    fn builds_the_same_tree_as_the_manual_with_children_calls() {
+        let via_macro = tree_format!(10, (5, (3), (7)), (20));
+
+        let manual = TreeNode::with_children(
+            10,
+            Some(TreeNode::with_children(5, Some(TreeNode::leaf(3)), Some(TreeNode::leaf(7)))),
+            Some(TreeNode::leaf(20)),
+        );
+
+        assert_eq!(via_macro, manual);
+    }
+
+    #[test]
+    fn a_bare_value_builds_a_leaf() {
+        assert_eq!(tree_format!(42), TreeNode::leaf(42));
+    }
+}
@@ -0,0 +1,141 @@
+//! A prefix tree (trie) over `char`s, used to answer prefix queries in
+//! `O(prefix length + matches)` instead of scanning every word.
+
+use std::collections::HashMap;
+
+#[derive(Default)]
+struct TrieNode {
+    children: HashMap<char, Box<TrieNode>>,
+    is_word_end: bool,
+    frequency: u32,
+}
+
+#[derive(Default)]
+pub struct Trie {
+    root: TrieNode,
+}
+
+impl Trie {
+    pub fn new() -> Self {
+        Trie::default()
+    }
+
+    pub fn insert(&mut self, word: &str) {
+        let mut node = &mut self.root;
+        for c in word.chars() {
+            node = node.children.entry(c).or_default();
+        }
+        node.is_word_end = true;
+    }
+
+    /// Inserts `word` with a frequency count, for use with [`Trie::suggest`].
+    pub fn insert_weighted(&mut self, word: &str, count: u32) {
+        let mut node = &mut self.root;
+        for c in word.chars() {
+            node = node.children.entry(c).or_default();
+        }
+        node.is_word_end = true;
+        node.frequency = count;
+    }
+
+    pub fn contains(&self, word: &str) -> bool {
+        self.find_node(word).is_some_and(|node| node.is_word_end)
+    }
+
+    /// Returns the top `n` completions of `prefix`, ranked by descending
+    /// frequency (as set via [`Trie::insert_weighted`]).
+    pub fn suggest(&self, prefix: &str, n: usize) -> Vec<(String, u32)> {
+        let Some(node) = self.find_node(prefix) else {
+            return Vec::new();
+        };
+        let mut results = Vec::new();
+        collect_weighted_words(node, prefix, &mut results);
+        results.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        results.truncate(n);
+        results
+    }
+
+    /// Returns every inserted word that starts with `prefix`.
+    pub fn words_with_prefix(&self, prefix: &str) -> Vec<String> {
+        let Some(node) = self.find_node(prefix) else {
+            return Vec::new();
+        };
+        let mut results = Vec::new();
+        collect_words(node, prefix, &mut results);
+        results
+    }
+
+    fn find_node(&self, prefix: &str) -> Option<&TrieNode> {
+        let mut node = &self.root;
+        for c in prefix.chars() {
+            node = node.children.get(&c)?;
+        }
+        Some(node)
+    }
+}
+
+fn collect_words(node: &TrieNode, prefix: &str, results: &mut Vec<String>) {
+    if node.is_word_end {
+        results.push(prefix.to_string());
+    }
+    for (c, child) in &node.children {
+        collect_words(child, &format!("{prefix}{c}"), results);
+    }
+}
+
+fn collect_weighted_words(node: &TrieNode, prefix: &str, results: &mut Vec<(String, u32)>) {
+    if node.is_word_end {
+        results.push((prefix.to_string(), node.frequency));
+    }
+    for (c, child) in &node.children {
+        collect_weighted_words(child, &format!("{prefix}{c}"), results);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_trie() -> Trie {
+        let mut trie = Trie::new();
+        for word in ["world", "wonderful", "hello", "help"] {
+            trie.insert(word);
+        }
+        trie
+    }
+
+    #[test]
+    fn words_with_prefix_finds_matching_inserted_words() {
+        let trie = sample_trie();
+        let mut matches = trie.words_with_prefix("wo");
+        matches.sort();
+        assert_eq!(matches, vec!["wonderful", "world"]);
+    }
+
+    #[test]
+    fn words_with_prefix_is_empty_for_unknown_prefix() {
+        let trie = sample_trie();
+        assert!(trie.words_with_prefix("x").is_empty());
+    }
+
+    #[test]
+    fn contains_only_matches_full_words() {
+        let trie = sample_trie();
+        assert!(trie.contains("help"));
+        assert!(!trie.contains("hel"));
+    }
+
+    #[test]
+    fn suggest_ranks_completions_by_descending_frequency() {
+        let mut trie = Trie::new();
+        trie.insert_weighted("cat", 5);
+        trie.insert_weighted("car", 20);
+        trie.insert_weighted("cart", 12);
+        trie.insert_weighted("dog", 100);
+
+        assert_eq!(
+            trie.suggest("ca", 2),
+            vec![("car".to_string(), 20), ("cart".to_string(), 12)]
+        );
+    }
+}
@@ -0,0 +1,67 @@
+//! A heterogeneous map storing at most one value per type, useful for
+//! request-scoped context where the set of extension types isn't known
+//! up front.
+
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+
+#[derive(Default)]
+pub struct TypeMap {
+    entries: HashMap<TypeId, Box<dyn Any>>,
+}
+
+impl TypeMap {
+    pub fn new() -> Self {
+        TypeMap {
+            entries: HashMap::new(),
+        }
+    }
+
+    /// Stores `value`, replacing any existing value of the same type.
+    pub fn insert<T: 'static>(&mut self, value: T) {
+        self.entries.insert(TypeId::of::<T>(), Box::new(value));
+    }
+
+    pub fn get<T: 'static>(&self) -> Option<&T> {
+        self.entries
+            .get(&TypeId::of::<T>())
+            .and_then(|value| value.downcast_ref())
+    }
+
+    pub fn remove<T: 'static>(&mut self) -> Option<T> {
+        self.entries
+            .remove(&TypeId::of::<T>())
+            .and_then(|value| value.downcast().ok())
+            .map(|value| *value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stores_and_retrieves_one_value_per_type() {
+        let mut map = TypeMap::new();
+        map.insert(42u32);
+        map.insert("hello".to_string());
+
+        assert_eq!(map.get::<u32>(), Some(&42));
+        assert_eq!(map.get::<String>(), Some(&"hello".to_string()));
+    }
+
+    #[test]
+    fn an_absent_type_returns_none() {
+        let map = TypeMap::new();
+        assert_eq!(map.get::<u32>(), None);
+    }
+
+    #[test]
+    fn remove_takes_ownership_and_clears_the_entry() {
+        let mut map = TypeMap::new();
+        map.insert(7i64);
+
+        assert_eq!(map.remove::<i64>(), Some(7));
+        assert_eq!(map.get::<i64>(), None);
+    }
+}
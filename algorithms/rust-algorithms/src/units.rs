@@ -0,0 +1,70 @@
+//! Unit-safe newtypes for small-scale length measurements. Mixing units
+//! without an explicit conversion doesn't compile — there's no `Add` impl
+//! across `Millimeters` and `Meters`.
+
+use std::ops::Add;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Millimeters(pub u32);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Meters(pub u32);
+
+impl From<Meters> for Millimeters {
+    fn from(m: Meters) -> Self {
+        Millimeters(m.0 * 1_000)
+    }
+}
+
+impl Millimeters {
+    /// Adds two lengths, returning `None` on overflow instead of panicking.
+    pub fn checked_add(self, other: Millimeters) -> Option<Millimeters> {
+        self.0.checked_add(other.0).map(Millimeters)
+    }
+}
+
+impl Add for Millimeters {
+    type Output = Millimeters;
+
+    fn add(self, other: Millimeters) -> Millimeters {
+        Millimeters(self.0 + other.0)
+    }
+}
+
+impl Meters {
+    pub fn checked_add(self, other: Meters) -> Option<Meters> {
+        self.0.checked_add(other.0).map(Meters)
+    }
+}
+
+impl Add for Meters {
+    type Output = Meters;
+
+    fn add(self, other: Meters) -> Meters {
+        Meters(self.0 + other.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adds_within_the_same_unit() {
+        assert_eq!(Millimeters(300) + Millimeters(200), Millimeters(500));
+        assert_eq!(Meters(3) + Meters(2), Meters(5));
+    }
+
+    #[test]
+    fn converts_meters_to_millimeters() {
+        let converted: Millimeters = Meters(2).into();
+        assert_eq!(converted, Millimeters(2_000));
+        assert_eq!(converted + Millimeters(500), Millimeters(2_500));
+    }
+
+    #[test]
+    fn checked_add_reports_overflow() {
+        assert_eq!(Millimeters(u32::MAX).checked_add(Millimeters(1)), None);
+        assert_eq!(Millimeters(1).checked_add(Millimeters(1)), Some(Millimeters(2)));
+    }
+}
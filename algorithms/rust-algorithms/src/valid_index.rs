@@ -0,0 +1,89 @@
+//! An index type that can only exist for a length it has already been
+//! checked against. The borrow in [`ValidIndex`] ties it to *a* collection
+//! of that type, but not to the specific instance it was validated
+//! against — a `ValidIndex` checked against a longer collection can still
+//! be passed to a shorter one of the same type, so indexing still goes
+//! through a real bounds check rather than `get_unchecked`.
+
+use std::ops::Index;
+
+pub struct Collection<T> {
+    items: Vec<T>,
+}
+
+/// An index known to be in bounds for *some* [`Collection<T>`] at the time
+/// it was checked. The borrowed lifetime only proves the collection used
+/// for the check outlives the `ValidIndex`; it does not tie the index to
+/// that specific collection, so it can still be checked against one
+/// instance and used on a shorter one of the same type.
+pub struct ValidIndex<'a, T> {
+    index: usize,
+    _collection: &'a Collection<T>,
+}
+
+impl<T> Collection<T> {
+    pub fn new(items: Vec<T>) -> Self {
+        Collection { items }
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Validates `index` against this collection's current length,
+    /// returning a [`ValidIndex`] borrowing `self` on success.
+    pub fn check_index(&self, index: usize) -> Option<ValidIndex<'_, T>> {
+        if index < self.items.len() {
+            Some(ValidIndex {
+                index,
+                _collection: self,
+            })
+        } else {
+            None
+        }
+    }
+}
+
+impl<T> Index<ValidIndex<'_, T>> for Collection<T> {
+    type Output = T;
+
+    fn index(&self, valid: ValidIndex<'_, T>) -> &T {
+        // `valid` isn't tied to this particular `self`, only to some
+        // `Collection<T>`, so we still bounds-check against `self.items`
+        // rather than trusting `valid.index` with `get_unchecked`.
+        &self.items[valid.index]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_valid_index_can_be_used_to_index_its_collection() {
+        let collection = Collection::new(vec!["a", "b", "c"]);
+        let valid = collection.check_index(1).expect("1 is in bounds");
+
+        assert_eq!(collection[valid], "b");
+    }
+
+    #[test]
+    fn an_out_of_range_index_check_returns_none() {
+        let collection = Collection::new(vec!["a", "b", "c"]);
+        assert!(collection.check_index(3).is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn a_valid_index_checked_against_a_longer_collection_panics_on_a_shorter_one() {
+        let big = Collection::new(vec![1, 2, 3, 4, 5]);
+        let valid = big.check_index(4).expect("4 is in bounds");
+
+        let small = Collection::new(vec![42]);
+        let _ = small[valid];
+    }
+}
@@ -0,0 +1,172 @@
+//! A semver-like version type for a package-manager-style example:
+//! parsing, precedence ordering, and caret (`^`) compatibility.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Version {
+    pub major: u64,
+    pub minor: u64,
+    pub patch: u64,
+    pub pre: Option<String>,
+}
+
+impl Version {
+    pub fn new(major: u64, minor: u64, patch: u64) -> Self {
+        Version { major, minor, patch, pre: None }
+    }
+
+    /// Caret (`^`) compatibility: `self` may be used where `other` was
+    /// requested if they share the same left-most nonzero component and
+    /// `self` is not older than `other`, following npm/cargo semantics.
+    pub fn is_compatible_with(&self, other: &Version) -> bool {
+        if self < other {
+            return false;
+        }
+
+        if other.major != 0 {
+            self.major == other.major
+        } else if other.minor != 0 {
+            self.major == 0 && self.minor == other.minor
+        } else {
+            self.major == 0 && self.minor == 0 && self.patch == other.patch
+        }
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)?;
+        if let Some(pre) = &self.pre {
+            write!(f, "-{pre}")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseVersionError {
+    pub input: String,
+}
+
+impl fmt::Display for ParseVersionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "`{}` is not a valid version", self.input)
+    }
+}
+
+impl std::error::Error for ParseVersionError {}
+
+impl FromStr for Version {
+    type Err = ParseVersionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || ParseVersionError { input: s.to_string() };
+
+        let (numbers, pre) = match s.split_once('-') {
+            Some((numbers, pre)) => (numbers, Some(pre.to_string())),
+            None => (s, None),
+        };
+
+        let parts: Vec<&str> = numbers.split('.').collect();
+        if parts.len() != 3 {
+            return Err(invalid());
+        }
+
+        let major = parts[0].parse().map_err(|_| invalid())?;
+        let minor = parts[1].parse().map_err(|_| invalid())?;
+        let patch = parts[2].parse().map_err(|_| invalid())?;
+
+        Ok(Version { major, minor, patch, pre })
+    }
+}
+
+impl PartialOrd for Version {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Version {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.major, self.minor, self.patch)
+            .cmp(&(other.major, other.minor, other.patch))
+            .then_with(|| match (&self.pre, &other.pre) {
+                // A pre-release always sorts before its release.
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (Some(a), Some(b)) => a.cmp(b),
+                (None, None) => Ordering::Equal,
+            })
+    }
+}
+
+/// A caret (`^`) version requirement, e.g. `^1.2.3`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionReq {
+    pub base: Version,
+}
+
+impl VersionReq {
+    pub fn matches(&self, candidate: &Version) -> bool {
+        candidate.is_compatible_with(&self.base)
+    }
+}
+
+impl FromStr for VersionReq {
+    type Err = ParseVersionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let base = s.strip_prefix('^').unwrap_or(s).parse()?;
+        Ok(VersionReq { base })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_a_release_and_a_pre_release_version() {
+        assert_eq!("1.2.3".parse(), Ok(Version::new(1, 2, 3)));
+        assert_eq!(
+            "1.2.3-alpha".parse(),
+            Ok(Version { major: 1, minor: 2, patch: 3, pre: Some("alpha".to_string()) })
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_input() {
+        assert!("1.2".parse::<Version>().is_err());
+        assert!("1.2.x".parse::<Version>().is_err());
+    }
+
+    #[test]
+    fn a_pre_release_sorts_before_its_release() {
+        let pre: Version = "1.2.3-alpha".parse().unwrap();
+        let release: Version = "1.2.3".parse().unwrap();
+        assert!(pre < release);
+    }
+
+    #[test]
+    fn caret_compatibility_allows_matching_leftmost_nonzero_component() {
+        let requested = Version::new(1, 2, 3);
+        assert!(Version::new(1, 5, 0).is_compatible_with(&requested));
+        assert!(!Version::new(2, 0, 0).is_compatible_with(&requested));
+        assert!(!Version::new(1, 2, 2).is_compatible_with(&requested));
+
+        let zero_major = Version::new(0, 2, 3);
+        assert!(Version::new(0, 2, 9).is_compatible_with(&zero_major));
+        assert!(!Version::new(0, 3, 0).is_compatible_with(&zero_major));
+    }
+
+    #[test]
+    fn a_caret_requirement_matches_compatible_versions_only() {
+        let req: VersionReq = "^1.2.0".parse().unwrap();
+        assert!(req.matches(&Version::new(1, 3, 0)));
+        assert!(!req.matches(&Version::new(2, 0, 0)));
+        assert!(!req.matches(&Version::new(1, 1, 0)));
+    }
+}
@@ -0,0 +1,55 @@
+//! A cache that holds only `Weak` references, so cached values are
+//! dropped as soon as no strong reference elsewhere keeps them alive —
+//! useful for large shared objects the cache shouldn't be the reason
+//! stay resident.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::rc::{Rc, Weak};
+
+#[derive(Default)]
+pub struct WeakCache<K: Eq + Hash, V> {
+    entries: HashMap<K, Weak<V>>,
+}
+
+impl<K: Eq + Hash, V> WeakCache<K, V> {
+    pub fn new() -> Self {
+        WeakCache {
+            entries: HashMap::new(),
+        }
+    }
+
+    pub fn insert(&mut self, key: K, value: &Rc<V>) {
+        self.entries.insert(key, Rc::downgrade(value));
+    }
+
+    /// Returns the cached value if it's still alive elsewhere, upgrading
+    /// the stored `Weak`. Returns `None` (and drops the stale entry) once
+    /// every strong reference has gone away.
+    pub fn get(&mut self, key: &K) -> Option<Rc<V>> {
+        match self.entries.get(key)?.upgrade() {
+            Some(value) => Some(value),
+            None => {
+                self.entries.remove(key);
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_returns_none_once_the_external_strong_reference_is_dropped() {
+        let mut cache = WeakCache::new();
+        let value = Rc::new(String::from("large object"));
+        cache.insert("key", &value);
+
+        assert_eq!(cache.get(&"key").as_deref(), Some(&"large object".to_string()));
+
+        drop(value);
+        assert_eq!(cache.get(&"key"), None);
+    }
+}
@@ -0,0 +1,86 @@
+//! A weighted random sampler for simulation examples: picks one of a
+//! fixed set of items with probability proportional to its weight.
+
+use crate::rng::Rng;
+
+/// Returned when constructing a [`WeightedSampler`] from weights that
+/// can't produce a valid distribution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidWeights;
+
+pub struct WeightedSampler<T> {
+    items: Vec<T>,
+    cumulative: Vec<f64>,
+    total: f64,
+}
+
+impl<T> WeightedSampler<T> {
+    /// Builds a sampler from `(item, weight)` pairs. Rejects negative
+    /// weights and a total weight of zero, since neither describes a
+    /// sampleable distribution.
+    pub fn new(weighted: Vec<(T, f64)>) -> Result<Self, InvalidWeights> {
+        if weighted.iter().any(|(_, weight)| *weight < 0.0) {
+            return Err(InvalidWeights);
+        }
+
+        let mut items = Vec::with_capacity(weighted.len());
+        let mut cumulative = Vec::with_capacity(weighted.len());
+        let mut total = 0.0;
+        for (item, weight) in weighted {
+            total += weight;
+            items.push(item);
+            cumulative.push(total);
+        }
+
+        if total <= 0.0 {
+            return Err(InvalidWeights);
+        }
+
+        Ok(WeightedSampler {
+            items,
+            cumulative,
+            total,
+        })
+    }
+
+    /// Draws one item, in O(log n) via a binary search over the
+    /// cumulative weights.
+    pub fn sample<R: Rng>(&self, rng: &mut R) -> &T {
+        let target = rng.next_f64() * self.total;
+        let index = self.cumulative.partition_point(|&c| c <= target);
+        &self.items[index.min(self.items.len() - 1)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::Lcg;
+
+    #[test]
+    fn negative_weights_are_rejected() {
+        assert!(WeightedSampler::new(vec![("a", 1.0), ("b", -1.0)]).is_err());
+    }
+
+    #[test]
+    fn all_zero_weights_are_rejected() {
+        assert!(WeightedSampler::new(vec![("a", 0.0), ("b", 0.0)]).is_err());
+    }
+
+    #[test]
+    fn empirical_ratio_converges_to_the_configured_weights() {
+        let sampler = WeightedSampler::new(vec![("heavy", 9.0), ("light", 1.0)]).unwrap();
+        let mut rng = Lcg::new(1234);
+
+        let mut heavy_count = 0;
+        let trials = 100_000;
+        for _ in 0..trials {
+            if *sampler.sample(&mut rng) == "heavy" {
+                heavy_count += 1;
+            }
+        }
+
+        let ratio = heavy_count as f64 / trials as f64;
+        assert!((ratio - 0.9).abs() < 0.01, "ratio was {ratio}");
+    }
+}
@@ -0,0 +1,6 @@
+use rust_algorithms::config::ConfigBuilder;
+
+fn main() {
+    // Missing `.url(..)`, so `build()` doesn't exist on this typestate.
+    let _config = ConfigBuilder::new().key("secret").build();
+}
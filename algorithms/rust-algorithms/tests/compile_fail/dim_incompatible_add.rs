@@ -0,0 +1,7 @@
+use rust_algorithms::dim::{Length, Time};
+
+fn main() {
+    // `Add` is only implemented for `Dim<L, T> + Dim<L, T>`, so a length
+    // and a time (different `L`/`T`) don't share an `Add` impl.
+    let _sum = Length::new(1.0) + Time::new(1.0);
+}
@@ -0,0 +1,6 @@
+use rust_algorithms::request_builder::RequestBuilder;
+
+fn main() {
+    // Missing `.method(..)`, so `build()` doesn't exist on this typestate.
+    let _request = RequestBuilder::new().url("https://example.com").build();
+}
@@ -0,0 +1,13 @@
+use rust_algorithms::sealed::Format;
+
+struct Xml;
+
+// `Sealed` is private to `rust_algorithms::sealed`, so this can't compile
+// outside the crate.
+impl Format for Xml {
+    fn serialize(&self, _fields: &[(&str, &str)]) -> String {
+        String::new()
+    }
+}
+
+fn main() {}